@@ -29,6 +29,17 @@ pub fn get(slider: &NoUiSlider) -> Vec<f64> {
         .collect::<Vec<f64>>()
 }
 
+// Get non-formatted values, also for single handle sliders
+pub fn get_unencoded(slider: &NoUiSlider) -> Vec<f64> {
+    let values = slider.get_raw(true);
+    match js_sys::try_iter(&values) {
+        Ok(Some(iter)) => iter
+            .map(|i| i.unwrap().as_f64().unwrap())
+            .collect::<Vec<f64>>(),
+        _ => values.as_f64().into_iter().collect(),
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[derive(Debug)]
@@ -55,6 +66,10 @@ extern "C" {
     #[wasm_bindgen(method, js_name = get)]
     pub fn get_with_options(this: &NoUiSlider, no_formatting: bool) -> Vec<JsValue>;
 
+    // Get values, a single value instead of an array for one handle
+    #[wasm_bindgen(method, js_name = get)]
+    pub fn get_raw(this: &NoUiSlider, no_formatting: bool) -> JsValue;
+
     // Set callbacks for events: start, slide, drag, update, change, set, end
     #[wasm_bindgen(method)]
     pub fn on(this: &NoUiSlider, event_name: &str, handler: &JsValue);
@@ -104,4 +119,20 @@ extern "C" {
     // Reset slider values
     #[wasm_bindgen(method)]
     pub fn reset(this: &NoUiSlider) -> JsValue;
+
+    // Disable the slider
+    #[wasm_bindgen(method)]
+    pub fn disable(this: &NoUiSlider);
+
+    // Disable a single handle
+    #[wasm_bindgen(method, js_name = disable)]
+    pub fn disable_handle(this: &NoUiSlider, handle_number: i64);
+
+    // Enable the slider
+    #[wasm_bindgen(method)]
+    pub fn enable(this: &NoUiSlider);
+
+    // Enable a single handle
+    #[wasm_bindgen(method, js_name = enable)]
+    pub fn enable_handle(this: &NoUiSlider, handle_number: i64);
 }
@@ -0,0 +1,120 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+// #[derive(SliderValue)] for fieldless enums. Variants are placed on the track
+// by index in declaration order and labelled with their name, or with
+// #[slider(label = "...")]. With #[slider(display)] on the enum, also implements
+// Display with the labels.
+#[proc_macro_derive(SliderValue, attributes(slider))]
+pub fn derive_slider_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    slider_value(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn slider_value(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            name,
+            "SliderValue can only be derived for enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            name,
+            "SliderValue needs at least one variant",
+        ));
+    }
+    let mut display = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("slider"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("display") {
+                display = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `display`"))
+            }
+        })?;
+    }
+    let mut variants = vec![];
+    let mut labels = vec![];
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "SliderValue variants can not have fields",
+            ));
+        }
+        let mut label = variant.ident.to_string();
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("slider"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    label = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `label = \"...\"`"))
+                }
+            })?;
+        }
+        variants.push(&variant.ident);
+        labels.push(label);
+    }
+    let indices = (0..variants.len()).map(|index| index as f64);
+    let positions = 0..variants.len();
+    let last = variants[variants.len() - 1];
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let display = display.then(|| {
+        quote! {
+            impl #impl_generics ::std::fmt::Display for #name #type_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(&::nouislider_yew::SliderValue::format(self))
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::nouislider_yew::SliderValue for #name #type_generics #where_clause {
+            fn to_f64(&self) -> f64 {
+                match self {
+                    #(Self::#variants => #indices,)*
+                }
+            }
+
+            fn from_f64(value: f64) -> Self {
+                // Positions past the ends belong to the first and last variant
+                match value.round().max(0.0) as usize {
+                    #(#positions => Self::#variants,)*
+                    _ => Self::#last,
+                }
+            }
+
+            fn format(&self) -> String {
+                match self {
+                    #(Self::#variants => #labels,)*
+                }
+                .to_string()
+            }
+
+            fn parse(text: &str) -> Option<Self> {
+                match text.trim() {
+                    #(#labels => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+        }
+
+        #display
+    })
+}
@@ -0,0 +1,224 @@
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono_tz::Europe::Stockholm;
+use chrono_tz::Tz;
+use nouislider_yew::{
+    AllocationSlider, Bins, CalendarUnit, CategorySlider, CrossfilterProvider, CrossfilterSlider,
+    Crossfiltered, DatePreset, DateSlider, DurationSlider, HistogramSlider, Log, Pips, RangeIndex,
+    RelativeRange, RowKey, SliderProps, SliderValue, TimeOfDaySlider, TrackMapping, TypedSlider,
+};
+use rand::Rng;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Clone, PartialEq, SliderValue)]
+#[slider(display)]
+enum Size {
+    XS,
+    S,
+    #[slider(label = "M")]
+    Medium,
+    L,
+    XL,
+}
+
+#[derive(PartialEq)]
+struct Sale {
+    price: f64,
+    rating: f64,
+}
+
+// Reads the rows left by the crossfilter sliders from their context
+#[function_component]
+fn SalesCount() -> Html {
+    let sales = use_context::<Crossfiltered<Sale>>().unwrap();
+    let crossfilter = sales.borrow();
+    let count = crossfilter.selected().count();
+    html! { <p>{count}{"/"}{crossfilter.rows().len()}{" sales in range"}</p> }
+}
+
+enum Msg {
+    DateUpdate(Vec<DateTime<Tz>>),
+}
+
+struct Model {
+    dates: RangeIndex<DateTime<Tz>>,
+    prices: Vec<f64>,
+    sales: Rc<Vec<Sale>>,
+    selected_dates: Vec<DateTime<Tz>>,
+}
+
+fn gen_random_dates(min: &DateTime<Tz>, max: &DateTime<Tz>, num: usize) -> Vec<DateTime<Tz>> {
+    let mut rng = rand::thread_rng();
+    let tz = min.timezone();
+    let min_ = min.timestamp();
+    let max_ = max.timestamp();
+    let mut dates: Vec<DateTime<Tz>> = (0..num - 2)
+        .map(|_| tz.timestamp_opt(rng.gen_range(min_..max_), 0).unwrap())
+        .collect();
+    dates.push(*min);
+    dates.push(*max);
+    dates.sort();
+    dates
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        let min = Stockholm.with_ymd_and_hms(2000, 3, 29, 12, 6, 43).unwrap();
+        let max = Stockholm.with_ymd_and_hms(2005, 12, 29, 4, 29, 15).unwrap();
+
+        let dates = RangeIndex::new(gen_random_dates(&min, &max, 500), |date| {
+            date.timestamp() as f64
+        });
+        // Sums of uniform samples pile up in the middle
+        let mut rng = rand::thread_rng();
+        let prices = (0..1000)
+            .map(|_| (0..3).map(|_| rng.gen_range(0.0..100.0)).sum())
+            .collect();
+        let sales = (0..1000)
+            .map(|_| Sale {
+                price: rng.gen_range(0.0..300.0),
+                rating: rng.gen_range(1..=5) as f64,
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            dates,
+            prices,
+            sales: Rc::new(sales),
+            selected_dates: vec![],
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DateUpdate(dates_range) => {
+                self.selected_dates = dates_range;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let output = match self.selected_dates.is_empty() {
+            false => {
+                let min = self.selected_dates.first().unwrap();
+                let max = self.selected_dates.get(1).unwrap();
+                let selected_count = self
+                    .dates
+                    .count(min.timestamp() as f64, max.timestamp() as f64);
+                html! { <p>{""}{selected_count}{"/"}{self.dates.len()}{" dates in range"}</p> }
+            }
+            true => html! {},
+        };
+
+        let min = self.dates.items().first().unwrap();
+        let max = self.dates.items().last().unwrap();
+        html! {
+            <div class="app">
+            <div class="section mx-6">
+                <DateSlider<DateTime<Tz>>
+                    min={*min}
+                    max={*max}
+                    start={vec![*min + (*max - *min) / 3, *max - (*max - *min) / 6]}
+                    step_unit={CalendarUnit::Day}
+                    min_span={Duration::days(7)}
+                    presets={vec![
+                        DatePreset::new("Last 30 days", RelativeRange::Last(std::time::Duration::from_secs(30 * 86400))),
+                        DatePreset::new("This quarter", RelativeRange::Current(CalendarUnit::Quarter)),
+                        DatePreset::new("Year to date", RelativeRange::ToDate(CalendarUnit::Year)),
+                        DatePreset::new("Last year", RelativeRange::Previous(CalendarUnit::Year)),
+                    ]}
+                    now={*max}
+                    onupdate={ctx.link().callback(Msg::DateUpdate)}
+                />
+            </div>
+            <div class="section mx-6">
+            {output}
+            </div>
+            <div class="section mx-6">
+                <HistogramSlider<f64>
+                    data={self.prices.clone()}
+                    min={0.0}
+                    max={300.0}
+                    bins={Bins::Width(10.0)}
+                    start={vec![100.0, 200.0]}
+                    formatter={Callback::from(|price: f64| format!("${:.0}", price))}
+                />
+            </div>
+            <div class="section mx-6">
+                <TypedSlider<f64>
+                    min={20.0}
+                    max={20000.0}
+                    start={vec![440.0]}
+                    mapping={TrackMapping::from(Log)}
+                    formatter={Callback::from(|hz: f64| format!("{:.0} Hz", hz))}
+                    options={SliderProps {
+                        tooltips: Some(true),
+                        pips: Some(Pips {
+                            mode: "values".to_string(),
+                            values: Some(vec![20.0, 100.0, 1000.0, 10000.0, 20000.0]),
+                            density: Some(100.0),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }}
+                />
+            </div>
+            <div class="section mx-6">
+                <DurationSlider<std::time::Duration>
+                    min={std::time::Duration::from_secs(5 * 60)}
+                    max={std::time::Duration::from_secs(90 * 86400)}
+                    start={vec![std::time::Duration::from_secs(3600)]}
+                />
+            </div>
+            <div class="section mx-6">
+                <TimeOfDaySlider<NaiveTime>
+                    start={vec![NaiveTime::from_hms_opt(22, 0, 0).unwrap(), NaiveTime::from_hms_opt(6, 0, 0).unwrap()]}
+                    wrap=true
+                />
+            </div>
+            <div class="section mx-6">
+                <CategorySlider<Size>
+                    items={vec![Size::XS, Size::S, Size::Medium, Size::L, Size::XL]}
+                    start={vec![Size::S, Size::L]}
+                />
+            </div>
+            <div class="section mx-6">
+                <AllocationSlider
+                    shares={vec![50.0, 30.0, 20.0]}
+                    labels={vec!["Design".to_string(), "Development".to_string(), "Marketing".to_string()]}
+                    minimums={vec![10.0, 20.0, 5.0]}
+                />
+            </div>
+            <div class="section mx-6">
+                <CrossfilterProvider<Sale> rows={self.sales.clone()}>
+                    <CrossfilterSlider<Sale>
+                        name="price"
+                        position={RowKey::from(|sale: &Sale| sale.price)}
+                        bins={Bins::Width(10.0)}
+                        formatter={Callback::from(|price: f64| format!("${:.0}", price))}
+                    />
+                    <CrossfilterSlider<Sale>
+                        name="rating"
+                        position={RowKey::from(|sale: &Sale| sale.rating)}
+                        bins={Bins::Count(5)}
+                        formatter={Callback::from(|rating: f64| format!("{:.1}", rating))}
+                    />
+                    <SalesCount />
+                </CrossfilterProvider<Sale>>
+            </div>
+            </div>
+        }
+    }
+}
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::new(log::Level::Trace));
+    yew::Renderer::<Model>::new().render();
+}
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::components::nouislider::Range;
+use crate::datetime::LocalDateTime;
+use crate::datetime::SliderDate;
+use crate::scale::ValueScale;
+
+// Calendar units in a time zone, weeks start on Monday
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CalendarUnit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl CalendarUnit {
+    // Start of the unit containing 'local'
+    pub fn truncate(self, local: LocalDateTime) -> LocalDateTime {
+        let LocalDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            ..
+        } = local;
+        match self {
+            CalendarUnit::Minute => LocalDateTime::new(year, month, day, hour, minute, 0),
+            CalendarUnit::Hour => LocalDateTime::new(year, month, day, hour, 0, 0),
+            CalendarUnit::Day => LocalDateTime::date(year, month, day),
+            CalendarUnit::Week => {
+                let monday = local.add_days(-(local.weekday() as i64));
+                LocalDateTime::date(monday.year, monday.month, monday.day)
+            }
+            CalendarUnit::Month => LocalDateTime::date(year, month, 1),
+            CalendarUnit::Quarter => LocalDateTime::date(year, (month - 1) / 3 * 3 + 1, 1),
+            CalendarUnit::Year => LocalDateTime::date(year, 1, 1),
+        }
+    }
+
+    // Add 'count' units to 'local'
+    pub fn add(self, local: LocalDateTime, count: u32) -> LocalDateTime {
+        match self {
+            CalendarUnit::Minute => local.add_seconds(60 * count as i64),
+            CalendarUnit::Hour => local.add_seconds(3600 * count as i64),
+            CalendarUnit::Day => local.add_days(count as i64),
+            CalendarUnit::Week => local.add_days(7 * count as i64),
+            CalendarUnit::Month => local.add_months(count),
+            CalendarUnit::Quarter => local.add_months(3 * count),
+            CalendarUnit::Year => local.add_months(12 * count),
+        }
+    }
+
+    // Average length in seconds
+    fn nominal_seconds(self) -> f64 {
+        match self {
+            CalendarUnit::Minute => 60.0,
+            CalendarUnit::Hour => 3600.0,
+            CalendarUnit::Day => 86400.0,
+            CalendarUnit::Week => 604800.0,
+            CalendarUnit::Month => 2629746.0,
+            CalendarUnit::Quarter => 7889238.0,
+            CalendarUnit::Year => 31556952.0,
+        }
+    }
+
+    // If 'local' is on a boundary of 'count' units, counted from the start of the next larger unit
+    fn is_aligned(self, local: LocalDateTime, count: u32) -> bool {
+        match self {
+            CalendarUnit::Minute => local.minute.is_multiple_of(count),
+            CalendarUnit::Hour => local.hour.is_multiple_of(count),
+            CalendarUnit::Month => (local.month - 1).is_multiple_of(count),
+            CalendarUnit::Year => local.year % count as i32 == 0,
+            _ => true,
+        }
+    }
+
+    // Units of equal length in seconds in every time zone with whole hour offsets
+    fn fixed_seconds(self) -> Option<f64> {
+        match self {
+            CalendarUnit::Minute => Some(60.0),
+            CalendarUnit::Hour => Some(3600.0),
+            _ => None,
+        }
+    }
+}
+
+// Seconds since the Unix epoch of local time in 'zone'. Ambiguous times resolve to
+// the earliest, times skipped by a daylight saving transition move forward to the
+// first valid time. Local times without a valid time in the next 24 hours, which
+// 'zone' can not represent, are read as UTC.
+pub fn from_local<D: SliderDate>(local: LocalDateTime, zone: &D::Zone) -> f64 {
+    (0..=96)
+        .find_map(|i| D::from_local(local.add_seconds(i * 15 * 60), zone))
+        .map_or(local.seconds() as f64, |date| date.timestamp())
+}
+
+// Unit boundaries in 'zone' strictly between 'min' and 'max', all in seconds since the Unix epoch
+pub fn boundaries<D: SliderDate>(
+    min: f64,
+    max: f64,
+    zone: &D::Zone,
+    unit: CalendarUnit,
+) -> impl Iterator<Item = f64> + '_ {
+    let start = unit.truncate(D::from_timestamp(min, zone).local());
+    std::iter::successors(Some(start), move |local| Some(unit.add(*local, 1)))
+        .map(move |local| from_local::<D>(local, zone))
+        .skip_while(move |date| *date <= min)
+        .take_while(move |date| *date < max)
+}
+
+// A date range relative to the current time
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelativeRange {
+    // The duration up to now, such as the last 24 hours
+    Last(Duration),
+    // The whole unit containing now, such as this month
+    Current(CalendarUnit),
+    // The start of the unit containing now up to now, such as year to date
+    ToDate(CalendarUnit),
+    // The whole unit before the one containing now, such as last month
+    Previous(CalendarUnit),
+}
+
+impl RelativeRange {
+    // Start and end of the range at 'now', calendar units are taken in the zone of 'now'
+    pub fn resolve<D: SliderDate>(&self, now: &D) -> (D, D) {
+        let zone = now.zone();
+        let local = now.local();
+        let date = |local| D::from_timestamp(from_local::<D>(local, &zone), &zone);
+        match *self {
+            RelativeRange::Last(duration) => {
+                let start = now.timestamp() - duration.as_secs() as f64;
+                (D::from_timestamp(start, &zone), now.clone())
+            }
+            RelativeRange::Current(unit) => {
+                let start = unit.truncate(local);
+                (date(start), date(unit.add(start, 1)))
+            }
+            RelativeRange::ToDate(unit) => (date(unit.truncate(local)), now.clone()),
+            RelativeRange::Previous(unit) => {
+                let end = unit.truncate(local);
+                let start = unit.truncate(end.add_seconds(-1));
+                (date(start), date(end))
+            }
+        }
+    }
+}
+
+// Time left out of a slider track, such as weekends, holidays and maintenance
+// windows. Track positions count included seconds only, so handles can not
+// stop inside an excluded interval.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExcludedTime {
+    intervals: Vec<(f64, f64)>, // Sorted and merged, in seconds since the Unix epoch
+    excluded: Vec<f64>,         // Seconds excluded before each interval, and in total
+    track: Vec<f64>,            // Track position of each interval
+}
+
+// Nothing excluded, the track is the time
+impl Default for ExcludedTime {
+    fn default() -> Self {
+        Self::from_seconds(std::iter::empty())
+    }
+}
+
+impl ExcludedTime {
+    pub fn new<D: SliderDate>(intervals: impl IntoIterator<Item = (D, D)>) -> Self {
+        Self::from_seconds(
+            (intervals.into_iter()).map(|(start, end)| (start.timestamp(), end.timestamp())),
+        )
+    }
+
+    // Intervals in seconds since the Unix epoch
+    fn from_seconds(intervals: impl Iterator<Item = (f64, f64)>) -> Self {
+        let mut sorted: Vec<(f64, f64)> = intervals.filter(|(start, end)| start < end).collect();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut intervals: Vec<(f64, f64)> = vec![];
+        for (start, end) in sorted {
+            match intervals.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => intervals.push((start, end)),
+            }
+        }
+        let mut excluded = vec![0.0];
+        let mut track = vec![];
+        for (start, end) in &intervals {
+            let before = *excluded.last().unwrap();
+            track.push(start - before);
+            excluded.push(before + end - start);
+        }
+        Self {
+            intervals,
+            excluded,
+            track,
+        }
+    }
+
+    // Saturdays and Sundays in 'zone' between 'min' and 'max'
+    pub fn weekends<D: SliderDate>(min: &D, max: &D, zone: &D::Zone) -> Self {
+        let start = CalendarUnit::Week.truncate(D::from_timestamp(min.timestamp(), zone).local());
+        let weeks = std::iter::successors(Some(start), |local| Some(local.add_days(7)));
+        let max = max.timestamp();
+        Self::from_seconds(
+            weeks
+                .map(|monday| {
+                    let saturday = from_local::<D>(monday.add_days(5), zone);
+                    let monday = from_local::<D>(monday.add_days(7), zone);
+                    (saturday, monday)
+                })
+                .take_while(|(saturday, _)| *saturday < max),
+        )
+    }
+
+    // The whole days containing 'dates' in their zones, such as holidays
+    pub fn days<D: SliderDate>(dates: &[D]) -> Self {
+        Self::from_seconds(dates.iter().map(|date| {
+            let zone = date.zone();
+            let day = CalendarUnit::Day.truncate(date.local());
+            let start = from_local::<D>(day, &zone);
+            let end = from_local::<D>(day.add_days(1), &zone);
+            (start, end)
+        }))
+    }
+
+    // Both the excluded time of self and other
+    pub fn union(&self, other: &ExcludedTime) -> Self {
+        Self::from_seconds(self.intervals.iter().chain(other.intervals.iter()).copied())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // If 'value' seconds since the Unix epoch is excluded
+    pub fn contains(&self, value: f64) -> bool {
+        let i = self.intervals.partition_point(|(start, _)| *start <= value);
+        i > 0 && value < self.intervals[i - 1].1
+    }
+
+    // Included seconds up to 'value', excluded intervals collapse to their start
+    pub fn to_track(&self, value: f64) -> f64 {
+        let i = self.intervals.partition_point(|(start, _)| *start < value);
+        match i > 0 && value < self.intervals[i - 1].1 {
+            true => self.intervals[i - 1].0 - self.excluded[i - 1],
+            false => value - self.excluded[i],
+        }
+    }
+
+    // Inverse of to_track, positions of collapsed intervals map to their start
+    pub fn from_track(&self, track: f64) -> f64 {
+        let i = self.track.partition_point(|start| *start < track);
+        track + self.excluded[i]
+    }
+}
+
+impl ValueScale for ExcludedTime {
+    fn to_track(&self, value: f64) -> f64 {
+        ExcludedTime::to_track(self, value)
+    }
+
+    fn to_value(&self, track: f64) -> f64 {
+        ExcludedTime::from_track(self, track)
+    }
+}
+
+fn percent(min: f64, max: f64, value: f64) -> String {
+    format!("{}%", (value - min) / (max - min) * 100.0)
+}
+
+// A linear range from 'min' to 'max' where handles can only stop on the
+// boundaries of 'unit' in 'zone', or at 'min' and 'max'. Units of varying length
+// get one sub-range per boundary, with the distance to the next as step.
+pub fn calendar_range<D: SliderDate>(
+    min: f64,
+    max: f64,
+    zone: &D::Zone,
+    unit: CalendarUnit,
+    excluded: &ExcludedTime,
+) -> Range {
+    let (min_f, max_f) = (excluded.to_track(min), excluded.to_track(max));
+    let dates = boundaries::<D>(min, max, zone, unit).map(|date| excluded.to_track(date));
+    let mut dates: Vec<f64> = match unit.fixed_seconds() {
+        // The first boundary is enough, noUiSlider steps on from there
+        Some(_) => dates.take(1).collect(),
+        None => dates.collect(),
+    };
+    // Times skipped by daylight saving or excluded can resolve to the same boundary
+    dates.dedup();
+    let mut range = HashMap::from([("max".to_string(), vec![max_f])]);
+    let first = dates.first().copied().unwrap_or(max_f);
+    range.insert("min".to_string(), vec![min_f, first - min_f]);
+    for (i, date) in dates.iter().enumerate() {
+        let next = dates.get(i + 1).copied().unwrap_or(max_f);
+        let step = unit.fixed_seconds().unwrap_or(next - date);
+        range.insert(percent(min_f, max_f, *date), vec![*date, step]);
+    }
+    Range(range)
+}
+
+// Tick intervals from fine to coarse
+const TICK_STEPS: [(CalendarUnit, u32); 20] = [
+    (CalendarUnit::Minute, 1),
+    (CalendarUnit::Minute, 5),
+    (CalendarUnit::Minute, 15),
+    (CalendarUnit::Minute, 30),
+    (CalendarUnit::Hour, 1),
+    (CalendarUnit::Hour, 3),
+    (CalendarUnit::Hour, 6),
+    (CalendarUnit::Hour, 12),
+    (CalendarUnit::Day, 1),
+    (CalendarUnit::Week, 1),
+    (CalendarUnit::Month, 1),
+    (CalendarUnit::Quarter, 1),
+    (CalendarUnit::Month, 6),
+    (CalendarUnit::Year, 1),
+    (CalendarUnit::Year, 2),
+    (CalendarUnit::Year, 5),
+    (CalendarUnit::Year, 10),
+    (CalendarUnit::Year, 25),
+    (CalendarUnit::Year, 50),
+    (CalendarUnit::Year, 100),
+];
+
+// Axis ticks between 'min' and 'max' on calendar boundaries in 'zone', using the
+// finest interval which gives at most 'max_ticks' ticks. Returns the unit of the
+// ticks, for tick_label.
+pub fn ticks<D: SliderDate>(
+    min: f64,
+    max: f64,
+    zone: &D::Zone,
+    max_ticks: usize,
+) -> (CalendarUnit, Vec<f64>) {
+    let span = max - min;
+    let (unit, count) = TICK_STEPS
+        .iter()
+        .copied()
+        .find(|(unit, count)| span / (unit.nominal_seconds() * *count as f64) <= max_ticks as f64)
+        .unwrap_or(TICK_STEPS[TICK_STEPS.len() - 1]);
+    let mut dates: Vec<f64> = boundaries::<D>(min, max, zone, unit)
+        .filter(|date| unit.is_aligned(D::from_timestamp(*date, zone).local(), count))
+        .collect();
+    dates.dedup();
+    (unit, dates)
+}
+
+// Short label for a tick of 'unit', the start of a larger unit shows that instead
+pub fn tick_label(local: LocalDateTime, unit: CalendarUnit) -> String {
+    let new_day = local.is_midnight();
+    let new_year = new_day && local.ordinal0() == 0;
+    match unit {
+        CalendarUnit::Minute | CalendarUnit::Hour if !new_day => {
+            format!("{:02}:{:02}", local.hour, local.minute)
+        }
+        CalendarUnit::Minute | CalendarUnit::Hour | CalendarUnit::Day | CalendarUnit::Week
+            if !new_year =>
+        {
+            format!("{} {}", local.month_name(), local.day)
+        }
+        CalendarUnit::Month | CalendarUnit::Quarter if !new_year => local.month_name().to_string(),
+        _ => local.year.to_string(),
+    }
+}
@@ -0,0 +1,202 @@
+use yew::prelude::*;
+
+use super::nouislider::Segment;
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+
+// Callbacks receive the shares of all segments in percent, summing to 100
+#[derive(Properties, Clone, PartialEq)]
+pub struct AllocationSliderProps {
+    // Initial shares, scaled to sum to 100. There is a segment per share and a
+    // handle between each pair of segments, fewer than two shares show nothing.
+    pub shares: Vec<f64>,
+    // Controlled shares
+    pub value: Option<Vec<f64>>,
+    // Segment names shown with the shares
+    #[prop_or_default]
+    pub labels: Vec<String>,
+    // Smallest share of each segment in percent, scaled down to fit when they
+    // sum to more than 100
+    #[prop_or_default]
+    pub minimums: Vec<f64>,
+    // Step of the shares in percent
+    #[prop_or(1.0)]
+    pub step: f64,
+    pub onupdate: Option<Callback<Vec<f64>>>,
+    pub onslide: Option<Callback<Vec<f64>>>,
+    pub onchange: Option<Callback<Vec<f64>>>,
+    pub onset: Option<Callback<Vec<f64>>>,
+    pub onstart: Option<Callback<Vec<f64>>>,
+    pub onend: Option<Callback<Vec<f64>>>,
+}
+
+pub enum Msg {
+    Update(TypedEvent<f64>),
+}
+
+// A slider splitting 100% into segments, such as a budget between teams. The
+// connect bars are the segments, labelled with their share, and handles stop
+// where a segment would fall below its minimum.
+pub struct AllocationSlider {
+    // Handle positions in percent
+    handles: Vec<f64>,
+}
+
+// Handle positions between segments of 'shares', equal segments when the
+// shares are all zero
+fn handles(shares: &[f64]) -> Vec<f64> {
+    let total: f64 = shares.iter().sum();
+    let count = shares.len().saturating_sub(1);
+    if total <= 0.0 {
+        let segments = shares.len() as f64;
+        return (1..=count).map(|i| i as f64 / segments * 100.0).collect();
+    }
+    let mut sum = 0.0;
+    (shares.iter().take(count))
+        .map(|share| {
+            sum += share;
+            sum / total * 100.0
+        })
+        .collect()
+}
+
+// Segment sizes between 'handles'
+fn shares(handles: &[f64]) -> Vec<f64> {
+    let mut edges = vec![0.0];
+    edges.extend_from_slice(handles);
+    edges.push(100.0);
+    edges.windows(2).map(|edge| edge[1] - edge[0]).collect()
+}
+
+// Minimum of each segment between 'handles', scaled down to sum to 100 at most
+fn fit(handles: &[f64], minimums: &[f64]) -> Vec<f64> {
+    let minimums: Vec<f64> = (0..=handles.len())
+        .map(|i| minimums.get(i).copied().unwrap_or(0.0).max(0.0))
+        .collect();
+    let total: f64 = minimums.iter().sum();
+    match total > 100.0 {
+        true => minimums
+            .iter()
+            .map(|minimum| minimum / total * 100.0)
+            .collect(),
+        false => minimums,
+    }
+}
+
+// Move handles only as far as the segments on both sides keep their minimum
+pub fn constrain(handles: &mut [f64], minimums: &[f64]) {
+    let minimums = fit(handles, minimums);
+    let mut previous = 0.0;
+    for (i, handle) in handles.iter_mut().enumerate() {
+        *handle = handle.max(previous + minimums[i]);
+        previous = *handle;
+    }
+    let mut next = 100.0;
+    for (i, handle) in handles.iter_mut().enumerate().rev() {
+        *handle = handle.min(next - minimums[i + 1]);
+        next = *handle;
+    }
+}
+
+// Convert a shares callback into a typed event callback
+fn reform(
+    minimums: &[f64],
+    callback: &Option<Callback<Vec<f64>>>,
+) -> Option<Callback<TypedEvent<f64>>> {
+    callback.as_ref().map(|cb| {
+        let minimums = minimums.to_vec();
+        cb.reform(move |mut event: TypedEvent<f64>| {
+            constrain(&mut event.values, &minimums);
+            shares(&event.values)
+        })
+    })
+}
+
+impl Component for AllocationSlider {
+    type Message = Msg;
+    type Properties = AllocationSliderProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let mut handles = handles(props.value.as_ref().unwrap_or(&props.shares));
+        constrain(&mut handles, &props.minimums);
+        Self { handles }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value || props.minimums != old_props.minimums {
+            if let Some(value) = &props.value {
+                self.handles = handles(value);
+            }
+            constrain(&mut self.handles, &props.minimums);
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Update(mut event) => {
+                // Handles past an inner minimum larger than the margin are moved back
+                // by the controlled value, also when it is the same as before
+                constrain(&mut event.values, &props.minimums);
+                self.handles = event.values;
+                if let Some(onupdate) = &props.onupdate {
+                    onupdate.emit(shares(&self.handles));
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        // noUiSlider needs a handle
+        if self.handles.is_empty() {
+            return html! {};
+        }
+        // Handles stop at the minimums of the outer segments, and at the smallest
+        // minimum of the inner segments, others are kept by constrain
+        let minimums = fit(&self.handles, &props.minimums);
+        let (first, last) = (minimums[0], minimums[minimums.len() - 1]);
+        let margin = (minimums[1..minimums.len() - 1].iter().copied()).reduce(f64::min);
+        let segments = (0..=self.handles.len())
+            .map(|i| {
+                let label = props.labels.get(i).cloned();
+                Segment {
+                    class: Some("allocation-segment".to_string()),
+                    formatter: Some(Callback::from(move |(from, to): (f64, f64)| match &label {
+                        Some(label) => format!("{} {:.0}%", label, to - from),
+                        None => format!("{:.0}%", to - from),
+                    })),
+                    ..Default::default()
+                }
+            })
+            .collect::<Vec<_>>();
+        let options = SliderProps {
+            step: Some(props.step),
+            margin: margin.filter(|margin| *margin > 0.0),
+            padding: Some(vec![first, last]),
+            connect: Some(vec![true; segments.len()]),
+            segments: Some(segments),
+            ..Default::default()
+        };
+
+        html! {
+            <TypedSlider<f64>
+                min={0.0}
+                max={100.0}
+                value={self.handles.clone()}
+                {options}
+                onupdate={ctx.link().callback(Msg::Update)}
+                onslide={reform(&props.minimums, &props.onslide)}
+                onchange={reform(&props.minimums, &props.onchange)}
+                onset={reform(&props.minimums, &props.onset)}
+                onstart={reform(&props.minimums, &props.onstart)}
+                onend={reform(&props.minimums, &props.onend)}
+            />
+        }
+    }
+}
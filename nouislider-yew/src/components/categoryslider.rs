@@ -0,0 +1,132 @@
+use std::fmt::Display;
+use std::rc::Rc;
+use yew::prelude::*;
+
+use super::nouislider::Pips;
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+
+// Callbacks receive the items at the handles of the corresponding slider event
+#[derive(Properties, Clone, PartialEq)]
+pub struct CategorySliderProps<T: Clone + PartialEq + Display + 'static> {
+    // Items in track order, labelled with Display
+    pub items: Vec<T>,
+    // Initial items at the handles, otherwise one handle at the first item
+    #[prop_or_default]
+    pub start: Vec<T>,
+    // Controlled items at the handles
+    pub value: Option<Vec<T>>,
+    pub onupdate: Option<Callback<Vec<T>>>,
+    pub onslide: Option<Callback<Vec<T>>>,
+    pub onchange: Option<Callback<Vec<T>>>,
+    pub onset: Option<Callback<Vec<T>>>,
+    pub onstart: Option<Callback<Vec<T>>>,
+    pub onend: Option<Callback<Vec<T>>>,
+}
+
+// A slider over discrete items such as sizes or release channels. The track
+// positions are item indices, with one step and one labelled pip per item.
+pub struct CategorySlider<T: Clone + PartialEq + Display + 'static> {
+    items: Rc<Vec<T>>,
+}
+
+// Item at a track position
+fn item<T: Clone>(items: &[T], index: f64) -> T {
+    let index = (index.round().max(0.0) as usize).min(items.len() - 1);
+    items[index].clone()
+}
+
+// Track positions of items, items not in 'items' are left out
+fn indices<T: PartialEq>(items: &[T], values: &[T]) -> Vec<f64> {
+    (values.iter())
+        .filter_map(|value| items.iter().position(|item| item == value))
+        .map(|index| index as f64)
+        .collect()
+}
+
+// Convert an item callback into a typed event callback
+fn reform<T: Clone + 'static>(
+    items: &Rc<Vec<T>>,
+    callback: &Option<Callback<Vec<T>>>,
+) -> Option<Callback<TypedEvent<f64>>> {
+    callback.as_ref().map(|cb| {
+        let items = items.clone();
+        cb.reform(move |event: TypedEvent<f64>| {
+            event
+                .values
+                .iter()
+                .map(|index| item(&items, *index))
+                .collect()
+        })
+    })
+}
+
+impl<T: Clone + PartialEq + Display + 'static> Component for CategorySlider<T> {
+    type Message = ();
+    type Properties = CategorySliderProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            items: Rc::new(ctx.props().items.clone()),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        if *self.items != ctx.props().items {
+            self.items = Rc::new(ctx.props().items.clone());
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let items = &self.items;
+        if items.is_empty() {
+            return html! {};
+        }
+        let mut start = indices(items, &props.start);
+        if start.is_empty() {
+            start.push(0.0);
+        }
+        let value = props.value.as_ref().map(|value| indices(items, value));
+        let handles = value.as_ref().map_or(start.len(), Vec::len);
+        // Connect the handles to each other
+        let connect = (0..=handles).map(|i| i > 0 && i < handles).collect();
+        // noUiSlider needs a range, a single item gets a track of two positions
+        let max = (items.len() - 1).max(1) as f64;
+        let options = SliderProps {
+            step: Some(1.0),
+            connect: Some(connect),
+            tooltips: Some(true),
+            pips: Some(Pips {
+                mode: "values".to_string(),
+                values: Some((0..items.len()).map(|index| index as f64).collect()),
+                density: Some(100.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let formatter = {
+            let items = items.clone();
+            Callback::from(move |index| item(&items, index).to_string())
+        };
+
+        html! {
+            <TypedSlider<f64>
+                min={0.0}
+                {max}
+                {start}
+                {value}
+                {options}
+                {formatter}
+                onupdate={reform(items, &props.onupdate)}
+                onslide={reform(items, &props.onslide)}
+                onchange={reform(items, &props.onchange)}
+                onset={reform(items, &props.onset)}
+                onstart={reform(items, &props.onstart)}
+                onend={reform(items, &props.onend)}
+            />
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use std::cell::Ref;
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::context::ContextHandle;
+use yew::prelude::*;
+
+use super::histogramslider::connect;
+use super::histogramslider::histogram_overlay;
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::crossfilter::Crossfilter;
+use crate::crossfilter::RowKey;
+use crate::data::Bins;
+
+// Context of a CrossfilterProvider with the rows inside all filters, consumers
+// update whenever a filter changes
+pub struct Crossfiltered<R: 'static> {
+    crossfilter: Rc<RefCell<Crossfilter<R>>>,
+    version: usize,
+    onchange: Callback<()>,
+}
+
+impl<R> Clone for Crossfiltered<R> {
+    fn clone(&self) -> Self {
+        Self {
+            crossfilter: self.crossfilter.clone(),
+            version: self.version,
+            onchange: self.onchange.clone(),
+        }
+    }
+}
+
+impl<R> PartialEq for Crossfiltered<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.crossfilter, &other.crossfilter) && self.version == other.version
+    }
+}
+
+impl<R> Crossfiltered<R> {
+    // The rows and filters, such as crossfilter.selected_rows() for a table
+    pub fn borrow(&self) -> Ref<'_, Crossfilter<R>> {
+        self.crossfilter.borrow()
+    }
+
+    // Id of the dimension called 'name', see Crossfilter::dimension
+    pub fn dimension(&self, name: &str, key: RowKey<R>) -> usize {
+        self.crossfilter.borrow_mut().dimension(name, key)
+    }
+
+    // Filter a dimension and update the consumers
+    pub fn filter(&self, id: usize, range: Option<(f64, f64)>) {
+        self.crossfilter.borrow_mut().filter(id, range);
+        self.onchange.emit(());
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CrossfilterProviderProps<R: PartialEq + 'static> {
+    pub rows: Rc<Vec<R>>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+pub enum ProviderMsg {
+    Changed,
+}
+
+// Shares rows between the CrossfilterSliders and other components inside it,
+// through a Crossfiltered<R> context
+pub struct CrossfilterProvider<R: PartialEq + 'static> {
+    crossfilter: Rc<RefCell<Crossfilter<R>>>,
+    version: usize,
+}
+
+impl<R: PartialEq + 'static> Component for CrossfilterProvider<R> {
+    type Message = ProviderMsg;
+    type Properties = CrossfilterProviderProps<R>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let crossfilter = Crossfilter::new(ctx.props().rows.clone());
+        Self {
+            crossfilter: Rc::new(RefCell::new(crossfilter)),
+            version: 0,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let rows = &ctx.props().rows;
+        if !Rc::ptr_eq(rows, &old_props.rows) {
+            self.crossfilter.borrow_mut().set_rows(rows.clone());
+            self.version += 1;
+        }
+        true
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            ProviderMsg::Changed => {
+                self.version += 1;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let context = Crossfiltered {
+            crossfilter: self.crossfilter.clone(),
+            version: self.version,
+            onchange: ctx.link().callback(|_| ProviderMsg::Changed),
+        };
+
+        html! {
+            <ContextProvider<Crossfiltered<R>> {context}>
+                { for ctx.props().children.iter() }
+            </ContextProvider<Crossfiltered<R>>>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CrossfilterSliderProps<R: PartialEq + 'static> {
+    // Name of the dimension, sliders with the same name share the filter
+    pub name: String,
+    // Position of a row on the track, read when the dimension is added
+    pub position: RowKey<R>,
+    // Track ends, otherwise the smallest and largest key
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    #[prop_or(Bins::Count(20))]
+    pub bins: Bins,
+    // Height of the tallest bar above the track in pixels
+    #[prop_or(60)]
+    pub height: u32,
+    pub formatter: Option<Callback<f64, String>>,
+    // Receives the handle values as the filter changes
+    pub onupdate: Option<Callback<Vec<f64>>>,
+}
+
+pub enum SliderMsg<R: 'static> {
+    Context(Crossfiltered<R>),
+    Update(TypedEvent<f64>),
+}
+
+// A range slider filtering the rows of the surrounding CrossfilterProvider by a
+// dimension. Its histogram counts the rows inside the filters of all other
+// dimensions, with the bins inside its own filter highlighted.
+pub struct CrossfilterSlider<R: PartialEq + 'static> {
+    context: Crossfiltered<R>,
+    _handle: ContextHandle<Crossfiltered<R>>,
+    dimension: usize,
+}
+
+impl<R: PartialEq + 'static> Component for CrossfilterSlider<R> {
+    type Message = SliderMsg<R>;
+    type Properties = CrossfilterSliderProps<R>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let (context, handle) = (ctx.link())
+            .context(ctx.link().callback(SliderMsg::Context))
+            .expect("CrossfilterSlider must be inside a CrossfilterProvider");
+        let dimension = context.dimension(&props.name, props.position.clone());
+        Self {
+            context,
+            _handle: handle,
+            dimension,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.name != old_props.name {
+            self.dimension = self.context.dimension(&props.name, props.position.clone());
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            SliderMsg::Context(context) => {
+                self.context = context;
+                true
+            }
+            SliderMsg::Update(event) => {
+                let low = event.values.iter().copied().fold(f64::INFINITY, f64::min);
+                let high = (event.values.iter().copied()).fold(f64::NEG_INFINITY, f64::max);
+                // The provider updates the context, and with it this slider
+                self.context.filter(self.dimension, Some((low, high)));
+                if let Some(onupdate) = &ctx.props().onupdate {
+                    onupdate.emit(event.values);
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let crossfilter = self.context.borrow();
+        let Some(extent) = crossfilter.extent(self.dimension) else {
+            return html! {};
+        };
+        let min = props.min.unwrap_or(extent.0);
+        // noUiSlider needs a range, a single key gets a track of one unit
+        let max = match props.max.unwrap_or(extent.1) {
+            max if max > min => max,
+            _ => min + 1.0,
+        };
+        let histogram = crossfilter.histogram(self.dimension, props.bins, min, max);
+        let selection = match crossfilter.filter_range(self.dimension) {
+            Some((low, high)) => vec![low, high],
+            None => vec![min, max],
+        };
+        let options = SliderProps {
+            connect: Some(connect(2)),
+            tooltips: Some(true),
+            ..Default::default()
+        };
+
+        let slider = html! {
+            <TypedSlider<f64>
+                {min}
+                {max}
+                start={vec![min, max]}
+                {options}
+                formatter={props.formatter.clone()}
+                onupdate={ctx.link().callback(SliderMsg::Update)}
+            />
+        };
+        histogram_overlay(&histogram, min, max, &selection, props.height, slider)
+    }
+}
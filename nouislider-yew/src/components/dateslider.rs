@@ -1,206 +1,289 @@
-use chrono::DateTime;
-use chrono::FixedOffset;
-use chrono::NaiveDateTime;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::marker::PhantomData;
+use std::time::Duration;
 use yew::prelude::*;
-use yew::Callback;
 
-use crate::ParentRef;
-
-use super::nouislider::Event as SliderEvent;
-use super::nouislider::FormattedValues;
 use super::nouislider::HandleAttributes;
 use super::nouislider::Pips;
-use super::nouislider::Range;
-use super::nouislider::Slider;
+use super::nouislider::SliderProps;
+use super::typedslider::TrackMapping;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::calendar::calendar_range;
+use crate::calendar::tick_label;
+use crate::calendar::ticks;
+use crate::calendar::CalendarUnit;
+use crate::calendar::ExcludedTime;
+use crate::calendar::RelativeRange;
+use crate::datetime::SliderDate;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DatePips {
+    None,
+    // At most this many pips on calendar boundaries, labelled by calendar unit
+    Calendar(usize),
+    // noUiSlider pips, labelled with 'format'
+    Custom(Pips),
+}
+
+// A quick select button setting both handles of a two handle slider
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatePreset {
+    pub label: String,
+    pub range: RelativeRange,
+}
+
+impl DatePreset {
+    pub fn new(label: &str, range: RelativeRange) -> Self {
+        Self {
+            label: label.to_string(),
+            range,
+        }
+    }
+
+    // Last 24 hours, last 7 days, this month and year to date
+    pub fn common() -> Vec<Self> {
+        vec![
+            Self::new(
+                "Last 24 hours",
+                RelativeRange::Last(Duration::from_secs(86400)),
+            ),
+            Self::new(
+                "Last 7 days",
+                RelativeRange::Last(Duration::from_secs(7 * 86400)),
+            ),
+            Self::new("This month", RelativeRange::Current(CalendarUnit::Month)),
+            Self::new("Year to date", RelativeRange::ToDate(CalendarUnit::Year)),
+        ]
+    }
+
+    // Handle dates at 'now', limited to 'min' and 'max'
+    pub fn resolve<D: SliderDate>(&self, now: &D, min: &D, max: &D) -> Vec<D> {
+        let (start, end) = self.range.resolve(now);
+        let clamp = |date: D| match date.timestamp() {
+            t if t < min.timestamp() => D::from_timestamp(min.timestamp(), &now.zone()),
+            t if t > max.timestamp() => D::from_timestamp(max.timestamp(), &now.zone()),
+            _ => date,
+        };
+        vec![clamp(start), clamp(end)]
+    }
+}
+
+// Callbacks receive the handle dates of the corresponding slider event, in 'timezone'
+#[derive(Properties, Clone, PartialEq)]
+pub struct DateSliderProps<D: SliderDate> {
+    pub min: D,
+    pub max: D,
+    // Time zone of tooltips, pips and emitted dates, defaults to the zone of 'min'
+    pub timezone: Option<D::Zone>,
+    // Initial handle dates, otherwise 'handles' dates spread evenly over the range
+    pub start: Option<Vec<D>>,
+    #[prop_or(2)]
+    pub handles: usize,
+    pub step: Option<D::Duration>,
+    // Step to calendar unit boundaries in 'timezone' instead of 'step'
+    pub step_unit: Option<CalendarUnit>,
+    // Minimum and maximum distance between handles
+    pub min_span: Option<D::Duration>,
+    pub max_span: Option<D::Duration>,
+    // Time the handles skip over, step and spans count included time only
+    #[prop_or_default]
+    pub excluded: ExcludedTime,
+    // Quick select buttons below the slider, shown with two handles
+    #[prop_or_default]
+    pub presets: Vec<DatePreset>,
+    // Time presets are relative to, defaults to the current time. Calendar
+    // presets such as this month are taken in 'timezone'.
+    pub now: Option<D>,
+    #[prop_or(DatePips::Calendar(8))]
+    pub pips: DatePips,
+    // Tooltip and pip format, a chrono strftime or time format description
+    #[prop_or_else(default_format::<D>)]
+    pub format: String,
+    pub onupdate: Option<Callback<Vec<D>>>,
+    pub onslide: Option<Callback<Vec<D>>>,
+    pub onchange: Option<Callback<Vec<D>>>,
+    pub onset: Option<Callback<Vec<D>>>,
+    pub onstart: Option<Callback<Vec<D>>>,
+    pub onend: Option<Callback<Vec<D>>>,
+}
 
-#[derive(Properties, Clone, PartialEq, Eq)]
-pub struct Options {
-    pub min: DateTime<FixedOffset>,
-    pub max: DateTime<FixedOffset>,
+fn default_format<D: SliderDate>() -> String {
+    D::DEFAULT_FORMAT.to_string()
 }
 
-#[derive(Clone, PartialEq)]
-pub struct SliderUpdateRef {
-    pub update: Callback<SliderEvent>,
+// Slider values are kept as seconds since the Unix epoch, and only converted to
+// the time zone for display. D is a chrono DateTime<Tz> with any TimeZone, such as
+// FixedOffset, Utc, Local or chrono_tz::Tz, or a time OffsetDateTime.
+pub struct DateSlider<D: SliderDate> {
+    // Last handle timestamps, set to a preset to move the handles
+    value: Option<Vec<f64>>,
+    _date: PhantomData<D>,
 }
 
 pub enum Msg {
-    ContextChanged(Rc<ParentRef>),
-    SliderUpdate(SliderEvent),
+    Update(TypedEvent<f64>),
+    Preset(usize),
 }
 
-pub struct DateSlider {
-    parent_state: Rc<ParentRef>,
-    _listener: ContextHandle<Rc<ParentRef>>,
-    state: Rc<SliderUpdateRef>,
-    slider_event: SliderEvent,
-    slider_values: FormattedValues,
-    tz: FixedOffset,
+fn timezone<D: SliderDate>(props: &DateSliderProps<D>) -> D::Zone {
+    props.timezone.clone().unwrap_or_else(|| props.min.zone())
 }
 
-fn from_timestamp(t: i64) -> String {
-    let naive = NaiveDateTime::from_timestamp(t, 0);
-    naive.format("%Y-%m-%d %H:%M").to_string()
+// Convert a date callback into a typed event callback
+fn reform<D: SliderDate>(
+    zone: &D::Zone,
+    callback: &Option<Callback<Vec<D>>>,
+) -> Option<Callback<TypedEvent<f64>>> {
+    callback.as_ref().map(|cb| {
+        let zone = zone.clone();
+        cb.reform(move |event: TypedEvent<f64>| {
+            event
+                .values
+                .iter()
+                .map(|timestamp| D::from_timestamp(*timestamp, &zone))
+                .collect()
+        })
+    })
 }
 
-impl Component for DateSlider {
+impl<D: SliderDate> Component for DateSlider<D> {
     type Message = Msg;
-    type Properties = Options;
+    type Properties = DateSliderProps<D>;
 
-    fn create(ctx: &Context<Self>) -> Self {
-        let update = ctx.link().callback(Msg::SliderUpdate);
-        let state = Rc::new(SliderUpdateRef { update });
-
-        let (parent_state, _listener) = ctx
-            .link()
-            .context::<Rc<ParentRef>>(ctx.link().callback(Msg::ContextChanged))
-            .expect("context to be set");
+    fn create(_ctx: &Context<Self>) -> Self {
         Self {
-            state,
-            parent_state,
-            _listener,
-            tz: ctx.props().min.timezone(),
-            slider_event: SliderEvent::default(),
-            slider_values: FormattedValues::default(),
+            value: None,
+            _date: PhantomData,
         }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
         match msg {
-            Msg::SliderUpdate(event) => {
-                self.slider_event = event;
-                self.slider_values.tooltips_text.clear();
-                self.slider_values.pips_text.clear();
-
-                let mut dates: Vec<DateTime<FixedOffset>> = vec![];
-                // Update the pips
-                for val in &self.slider_event.pips {
-                    let ts = *val as i64;
-                    let text = from_timestamp(ts);
-                    self.slider_values.pips_text.push(text);
+            Msg::Update(event) => {
+                // The controlled value follows the handles, and a preset moves them
+                // even when it was the last one clicked
+                self.value = Some(event.values.clone());
+                if let Some(onupdate) = reform(&timezone(props), &props.onupdate) {
+                    onupdate.emit(event);
                 }
-
-                // Update the tooltips
-                for val in &self.slider_event.unencoded {
-                    let ts = *val as i64;
-                    let text = from_timestamp(ts);
-                    self.slider_values.tooltips_text.push(text);
-
-                    let naive = NaiveDateTime::from_timestamp(ts, 0)
-                        .and_local_timezone(self.tz)
-                        .unwrap();
-                    dates.push(naive);
-                }
-                // Send values to parent component
-                let cb = self.parent_state.update.reform(move |update| (update));
-                cb.emit(dates);
                 true
             }
-            Msg::ContextChanged(state) => {
-                self.parent_state = state;
+            Msg::Preset(i) => {
+                let zone = timezone(props);
+                let now = match &props.now {
+                    Some(now) => D::from_timestamp(now.timestamp(), &zone),
+                    None => D::now(&zone),
+                };
+                let max = D::from_timestamp(props.max.timestamp() + 1.0, &zone);
+                let dates = props.presets[i].resolve(&now, &props.min, &max);
+                self.value = Some(dates.iter().map(D::timestamp).collect());
                 true
             }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let min = (ctx.props().min.timestamp() - self.tz.utc_minus_local() as i64) as f64;
-        let max = (ctx.props().max.timestamp() - self.tz.utc_minus_local() as i64 + 1) as f64;
-        let delta = max - min;
-        let range = HashMap::from([
-            ("min".to_string(), vec![min]),
-            ("max".to_string(), vec![max]),
-            // ("20%".to_string(), vec![min + (delta * 0.01)]),
-            // ("30%".to_string(), vec![min + (delta * 0.15)]),
-            // ("40%".to_string(), vec![min + (delta * 0.40)]),
-            // ("50%".to_string(), vec![min + (delta * 0.60)]),
-            // ("60%".to_string(), vec![min + (delta * 0.9)]),
-            // ("70%".to_string(), vec![min + (delta * 0.9)]),
-        ]);
-        let handle_attributes = vec![
-            HashMap::from([("aria-label".to_string(), "lower".to_string())]),
-            HashMap::from([("aria-label".to_string(), "upper".to_string())]),
-        ];
-        let pips = Pips {
-            mode: "positions".to_string(),
-            density: Some(1.0),
-            values: Some(vec![0.0, 25.0, 50.0, 75.0, 100.0]),
-            // stepped: None,
+        let props = ctx.props();
+        let tz = timezone(props);
+        let excluded = &props.excluded;
+        let min = props.min.timestamp();
+        let max = props.max.timestamp() + 1.0;
+        let (min_track, max_track) = (excluded.to_track(min), excluded.to_track(max));
+        let slider_start: Vec<f64> = match &props.start {
+            Some(start) => start.iter().map(D::timestamp).collect(),
+            None => (1..=props.handles)
+                .map(|i| {
+                    let fraction = i as f64 / (props.handles + 1) as f64;
+                    let track = min_track + (max_track - min_track) * fraction;
+                    excluded.from_track(track)
+                })
+                .collect(),
+        };
+        let handles = slider_start.len();
+        let handle_attributes = (0..handles)
+            .map(|i| {
+                let label = match (handles, i) {
+                    (2, 0) => "lower".to_string(),
+                    (2, _) => "upper".to_string(),
+                    _ => format!("handle {}", i + 1),
+                };
+                HashMap::from([("aria-label".to_string(), label)])
+            })
+            .collect();
+        // Connect the handles to each other
+        let connect = (0..=handles).map(|i| i > 0 && i < handles).collect();
+        let (pips, pip_formatter) = match &props.pips {
+            DatePips::None => (None, None),
+            DatePips::Custom(pips) => (Some(pips.clone()), None),
+            DatePips::Calendar(max_ticks) => {
+                let (unit, dates) = ticks::<D>(min, max, &tz, *max_ticks);
+                let values = (dates.into_iter())
+                    .filter(|date| !excluded.contains(*date))
+                    .collect();
+                let pips = Pips {
+                    mode: "values".to_string(),
+                    values: Some(values),
+                    ..Default::default()
+                };
+                let tz = tz.clone();
+                let pip_formatter = Callback::from(move |timestamp| {
+                    tick_label(D::from_timestamp(timestamp, &tz).local(), unit)
+                });
+                (Some(pips), Some(pip_formatter))
+            }
+        };
+        let mut options = SliderProps {
+            connect: Some(connect),
+            pips,
+            margin: props.min_span.as_ref().map(D::seconds),
+            limit: props.max_span.as_ref().map(D::seconds),
+            handle_attributes: Some(HandleAttributes(handle_attributes)),
+            tooltips: Some(true),
+            step: props.step.as_ref().map(D::seconds),
             ..Default::default()
         };
-        let slider_start = vec![min + delta / 3.0, min + delta / 1.2];
+        if let Some(unit) = props.step_unit {
+            options.range = calendar_range::<D>(min, max, &tz, unit, excluded);
+            options.step = None;
+        }
+        let formatter = {
+            let tz = tz.clone();
+            let format = props.format.clone();
+            Callback::from(move |timestamp| D::from_timestamp(timestamp, &tz).format_with(&format))
+        };
+
+        let presets = (props.presets.iter().enumerate()).map(|(i, preset)| {
+            html! {
+                <button type="button" onclick={ctx.link().callback(move |_| Msg::Preset(i))}>
+                    {&preset.label}
+                </button>
+            }
+        });
 
         html! {
             <>
-            <ContextProvider<Rc<SliderUpdateRef>> context={self.state.clone()}>
-                <Slider
-                    start={slider_start}
-                    connect={vec![false, true, false]}
-                    range={Range(range)}
-                    pips={pips}
-                    margin={delta/100.0}
-                    handle_attributes={HandleAttributes(handle_attributes)}
-                    tooltips={true}
-                    values={self.slider_values.clone()}
-                    // snap={true}
-                    step={(max as f64 - min as f64)/100.0}
-                    // limit={delta/1.3}
-                    // padding={vec![delta/10.0, delta/15.0]}
-                    // behaviour={"drag-fixed"}
-                    // orientation={"vertical"}
-                    // direction={"rtl"}
-                    // keyboard_support={false}
-                    // keyboard_default_step={100.0}
-                />
-            </ContextProvider<Rc<SliderUpdateRef>>>
-            // {self._draw_slider_data()}
+            <TypedSlider<f64>
+                {min}
+                {max}
+                start={slider_start}
+                value={self.value.clone()}
+                {options}
+                mapping={(!excluded.is_empty()).then(|| TrackMapping::from(excluded.clone()))}
+                {formatter}
+                {pip_formatter}
+                onupdate={ctx.link().callback(Msg::Update)}
+                onslide={reform(&tz, &props.onslide)}
+                onchange={reform(&tz, &props.onchange)}
+                onset={reform(&tz, &props.onset)}
+                onstart={reform(&tz, &props.onstart)}
+                onend={reform(&tz, &props.onend)}
+            />
+            if handles == 2 && !props.presets.is_empty() {
+                <div class="date-presets">{for presets}</div>
+            }
             </>
         }
     }
 }
-
-impl DateSlider {
-    fn _draw_slider_data(&self) -> Html {
-        let col0_width = format!("{:.0}%", 10f32);
-        let col_width = format!("{:.0}%", 90f32 / self.slider_event.values.len() as f32);
-        html! {
-            <div class="my-6">
-            <br />
-            <table width="100%" class="table is-bordered is-narrow is-fullwidth">
-            <tbody>
-                <tr>
-                    <td width={col0_width}>{"values"}</td>
-                    {
-                        self.slider_event.values.clone().into_iter().map(|value| {
-                            html!{<td width={col_width.clone()}>{value}</td> }
-                        }).collect::<Html>()
-                    }
-                </tr>
-                <tr>
-                    <td>{"unencoded"}</td>
-                    {
-                        self.slider_event.unencoded.clone().into_iter().map(|value| {
-                            html!{<td>{value}</td> }
-                        }).collect::<Html>()
-                    }
-                </tr>
-                <tr>
-                    <td>{"position"}</td>
-                    {
-                        self.slider_event.positions.clone().into_iter().map(|value| {
-                            html!{<td>{value}</td> }
-                        }).collect::<Html>()
-                    }
-                </tr>
-                <tr>
-                    <td>{"last handle"}</td>
-                    <td>{self.slider_event.handle}</td>
-                </tr>
-            </tbody>
-            </table>
-            </div>
-        }
-    }
-}
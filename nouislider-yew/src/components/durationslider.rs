@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use yew::prelude::*;
+
+use super::nouislider::Pips;
+use super::nouislider::Range;
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::value::SliderValue;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 3600.0;
+const DAY: f64 = 86400.0;
+
+// Range breakpoints in seconds, and the step from each breakpoint to the next
+const DURATION_STEPS: [(f64, f64); 14] = [
+    (1.0, 1.0),
+    (15.0, 5.0),
+    (MINUTE, 15.0),
+    (5.0 * MINUTE, MINUTE),
+    (15.0 * MINUTE, 5.0 * MINUTE),
+    (HOUR, 15.0 * MINUTE),
+    (6.0 * HOUR, HOUR),
+    (DAY, HOUR),
+    (3.0 * DAY, 6.0 * HOUR),
+    (7.0 * DAY, DAY),
+    (30.0 * DAY, DAY),
+    (90.0 * DAY, 7.0 * DAY),
+    (365.0 * DAY, 30.0 * DAY),
+    (5.0 * 365.0 * DAY, 365.0 * DAY),
+];
+
+// Durations labelled on the pips when in range
+const DURATION_PIPS: [f64; 7] = [
+    MINUTE,
+    HOUR,
+    DAY,
+    7.0 * DAY,
+    30.0 * DAY,
+    365.0 * DAY,
+    3650.0 * DAY,
+];
+
+// Logarithmic track position of 'seconds' between 'min' and 'max', in percent
+fn position(min: f64, max: f64, seconds: f64) -> f64 {
+    let log = |seconds: f64| seconds.ln_1p();
+    (log(seconds) - log(min)) / (log(max) - log(min)) * 100.0
+}
+
+// Step of the sub-range starting at 'seconds'
+fn step(seconds: f64) -> f64 {
+    (DURATION_STEPS.iter().rev())
+        .find(|(breakpoint, _)| *breakpoint <= seconds)
+        .map_or(1.0, |(_, step)| *step)
+}
+
+// A non-linear range from 'min' to 'max' seconds. Every breakpoint in between gets
+// a sub-range with a step fitting its size, and the track is logarithmic, so short
+// durations are as precise as long ones.
+pub fn duration_range(min: f64, max: f64) -> Range {
+    let mut range = HashMap::from([
+        ("min".to_string(), vec![min, step(min)]),
+        ("max".to_string(), vec![max]),
+    ]);
+    for (breakpoint, step) in DURATION_STEPS {
+        if min < breakpoint && breakpoint < max {
+            let key = format!("{}%", position(min, max, breakpoint));
+            range.insert(key, vec![breakpoint, step]);
+        }
+    }
+    Range(range)
+}
+
+// Callbacks receive the handle durations of the corresponding slider event
+#[derive(Properties, Clone, PartialEq)]
+pub struct DurationSliderProps<D: SliderValue> {
+    pub min: D,
+    pub max: D,
+    // Initial handle durations, otherwise one handle at 'min'
+    #[prop_or_default]
+    pub start: Vec<D>,
+    pub onupdate: Option<Callback<Vec<D>>>,
+    pub onslide: Option<Callback<Vec<D>>>,
+    pub onchange: Option<Callback<Vec<D>>>,
+    pub onset: Option<Callback<Vec<D>>>,
+    pub onstart: Option<Callback<Vec<D>>>,
+    pub onend: Option<Callback<Vec<D>>>,
+}
+
+// A slider for std::time::Duration, chrono::Duration or time::Duration, from
+// seconds to years, with humanized tooltips such as "1 hour 30 minutes".
+pub struct DurationSlider<D: SliderValue> {
+    _duration: PhantomData<D>,
+}
+
+fn reform<D: SliderValue>(callback: &Option<Callback<Vec<D>>>) -> Option<Callback<TypedEvent<D>>> {
+    (callback.as_ref()).map(|cb| cb.reform(|event: TypedEvent<D>| event.values))
+}
+
+impl<D: SliderValue> Component for DurationSlider<D> {
+    type Message = ();
+    type Properties = DurationSliderProps<D>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _duration: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let (min, max) = (props.min.to_f64(), props.max.to_f64());
+        let start = match props.start.is_empty() {
+            true => vec![props.min.clone()],
+            false => props.start.clone(),
+        };
+        // Connect a single handle to the start, or the handles to each other
+        let handles = start.len();
+        let connect = match handles {
+            1 => vec![true, false],
+            _ => (0..=handles).map(|i| i > 0 && i < handles).collect(),
+        };
+        let mut pips: Vec<f64> = (DURATION_PIPS.iter().copied())
+            .filter(|seconds| min < *seconds && *seconds < max)
+            .collect();
+        pips.insert(0, min);
+        pips.push(max);
+        let options = SliderProps {
+            range: duration_range(min, max),
+            connect: Some(connect),
+            tooltips: Some(true),
+            pips: Some(Pips {
+                mode: "values".to_string(),
+                values: Some(pips),
+                density: Some(100.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        html! {
+            <TypedSlider<D>
+                min={props.min.clone()}
+                max={props.max.clone()}
+                {start}
+                {options}
+                onupdate={reform(&props.onupdate)}
+                onslide={reform(&props.onslide)}
+                onchange={reform(&props.onchange)}
+                onset={reform(&props.onset)}
+                onstart={reform(&props.onstart)}
+                onend={reform(&props.onend)}
+            />
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::marker::PhantomData;
+use yew::prelude::*;
+
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::data::Bins;
+use crate::data::Histogram;
+use crate::value::SliderValue;
+
+// Callbacks receive the handle values of the corresponding slider event
+#[derive(Properties, Clone, PartialEq)]
+pub struct HistogramSliderProps<T: SliderValue> {
+    // Values counted in the histogram, values outside min to max are left out
+    pub data: Vec<T>,
+    pub min: T,
+    pub max: T,
+    #[prop_or(Bins::Count(20))]
+    pub bins: Bins,
+    // Height of the tallest bar above the track in pixels
+    #[prop_or(60)]
+    pub height: u32,
+    // Initial handle values, otherwise a range from min to max
+    #[prop_or_default]
+    pub start: Vec<T>,
+    pub value: Option<Vec<T>>,
+    // Tooltip and pip text, defaults to SliderValue::format
+    pub formatter: Option<Callback<T, String>>,
+    pub onupdate: Option<Callback<Vec<T>>>,
+    pub onslide: Option<Callback<Vec<T>>>,
+    pub onchange: Option<Callback<Vec<T>>>,
+    pub onset: Option<Callback<Vec<T>>>,
+    pub onstart: Option<Callback<Vec<T>>>,
+    pub onend: Option<Callback<Vec<T>>>,
+}
+
+pub enum Msg<T: SliderValue> {
+    Update(TypedEvent<T>),
+}
+
+// A slider with a histogram of a dataset drawn as bars behind the track. Bars of
+// bins in the selected range, between the first and the last handle or below a
+// single handle, get the "selected" class as the handles move.
+pub struct HistogramSlider<T: SliderValue> {
+    histogram: Histogram,
+    // Handle positions on the track
+    selection: Vec<f64>,
+    _value: PhantomData<T>,
+}
+
+fn histogram<T: SliderValue>(props: &HistogramSliderProps<T>) -> Histogram {
+    let values: Vec<f64> = props.data.iter().map(T::to_f64).collect();
+    Histogram::from_values(props.bins, props.min.to_f64(), props.max.to_f64(), &values)
+}
+
+fn start<T: SliderValue>(props: &HistogramSliderProps<T>) -> Vec<T> {
+    match props.start.is_empty() {
+        true => vec![props.min.clone(), props.max.clone()],
+        false => props.start.clone(),
+    }
+}
+
+// Connect the handles to each other, or a single handle to the lower end
+pub(crate) fn connect(handles: usize) -> Vec<bool> {
+    (0..=handles)
+        .map(|i| match handles {
+            1 => i == 0,
+            _ => i > 0 && i < handles,
+        })
+        .collect()
+}
+
+// Bars of a histogram over 'min' to 'max' behind the track of 'slider', rising
+// 'height' pixels above it. Bins in the range selected by handles at 'selection'
+// get the "selected" class.
+pub(crate) fn histogram_overlay(
+    histogram: &Histogram,
+    min: f64,
+    max: f64,
+    selection: &[f64],
+    height: u32,
+    slider: Html,
+) -> Html {
+    let low = match selection.len() {
+        1 => min,
+        _ => selection.iter().copied().fold(f64::INFINITY, f64::min),
+    };
+    let high = selection.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let tallest = histogram.max_count().max(1) as f64;
+    let edges = &histogram.edges;
+    let bars = histogram.counts.iter().enumerate().map(|(i, count)| {
+        let (from, to) = (edges[i], edges[i + 1]);
+        let style = format!(
+            "width: {}%; height: {}%",
+            (to - from) / (max - min) * 100.0,
+            *count as f64 / tallest * 100.0
+        );
+        let selected = from < high && low < to;
+        html! {
+            <div
+                class={classes!("histogram-bar", selected.then_some("selected"))}
+                {style}
+                title={count.to_string()}
+            />
+        }
+    });
+
+    // The slider comes later in the document and is drawn over the bars
+    html! {
+        <div class="histogram-slider" style={format!("position: relative; padding-top: {}px", height)}>
+            <div
+                class="histogram"
+                style="position: absolute; inset: 0; display: flex; align-items: flex-end; pointer-events: none"
+            >
+                { for bars }
+            </div>
+            <div style="position: relative">{ slider }</div>
+        </div>
+    }
+}
+
+// Convert a value callback into a typed event callback
+fn reform<T: SliderValue>(callback: &Option<Callback<Vec<T>>>) -> Option<Callback<TypedEvent<T>>> {
+    (callback.as_ref()).map(|cb| cb.reform(|event: TypedEvent<T>| event.values))
+}
+
+impl<T: SliderValue> Component for HistogramSlider<T> {
+    type Message = Msg<T>;
+    type Properties = HistogramSliderProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let selection = props.value.clone().unwrap_or_else(|| start(props));
+        Self {
+            histogram: histogram(props),
+            selection: selection.iter().map(T::to_f64).collect(),
+            _value: PhantomData,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.data != old_props.data
+            || props.min != old_props.min
+            || props.max != old_props.max
+            || props.bins != old_props.bins
+        {
+            self.histogram = histogram(props);
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Update(event) => {
+                self.selection = event.values.iter().map(T::to_f64).collect();
+                if let Some(onupdate) = &ctx.props().onupdate {
+                    onupdate.emit(event.values);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let (min, max) = (props.min.to_f64(), props.max.to_f64());
+        let options = SliderProps {
+            connect: Some(connect(self.selection.len())),
+            tooltips: Some(true),
+            ..Default::default()
+        };
+
+        let slider = html! {
+            <TypedSlider<T>
+                min={props.min.clone()}
+                max={props.max.clone()}
+                start={start(props)}
+                value={props.value.clone()}
+                {options}
+                formatter={props.formatter.clone()}
+                onupdate={ctx.link().callback(Msg::Update)}
+                onslide={reform(&props.onslide)}
+                onchange={reform(&props.onchange)}
+                onset={reform(&props.onset)}
+                onstart={reform(&props.onstart)}
+                onend={reform(&props.onend)}
+            />
+        };
+        histogram_overlay(
+            &self.histogram,
+            min,
+            max,
+            &self.selection,
+            props.height,
+            slider,
+        )
+    }
+}
@@ -1,2 +1,11 @@
+pub mod allocationslider;
+pub mod categoryslider;
+pub mod crossfilter;
 pub mod dateslider;
+pub mod durationslider;
+pub mod histogramslider;
 pub mod nouislider;
+pub mod snapslider;
+pub mod timeofdayslider;
+pub mod typedslider;
+pub mod use_slider;
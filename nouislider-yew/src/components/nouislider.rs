@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use gloo_utils::document;
 use nouislider as no;
 use serde::{Deserialize, Serialize};
@@ -12,14 +10,12 @@ use web_sys::HtmlElement;
 use web_sys::Node;
 use yew::prelude::*;
 
-use super::dateslider::SliderUpdateRef;
-
 // https://refreshless.com/nouislider/slider-options/#section-handle-attributes
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct HandleAttributes(pub Vec<HashMap<String, String>>);
 
 // https://refreshless.com/nouislider/slider-values/
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct Range(pub HashMap<String, Vec<f64>>);
 
 // https://refreshless.com/nouislider/pips/
@@ -31,6 +27,17 @@ pub struct Pips {
     pub stepped: Option<bool>,
 }
 
+// Appearance of a connect bar, by index in 'connect'. The label is centered on
+// the bar and follows the handles, 'formatter' receives the values at the ends
+// of the bar and replaces 'label'.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Segment {
+    pub class: Option<String>,
+    pub color: Option<String>,
+    pub label: Option<String>,
+    pub formatter: Option<Callback<(f64, f64), String>>,
+}
+
 // These strings will overwrite default tooltips and pips
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Default)]
 pub struct FormattedValues {
@@ -39,12 +46,18 @@ pub struct FormattedValues {
 }
 
 // https://refreshless.com/nouislider/slider-options/
-#[derive(Properties, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Options {
+#[derive(Properties, Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SliderProps {
+    #[prop_or_default]
     pub values: FormattedValues, // Parent override tooltips and pips
-    pub start: Vec<f64>,
+    #[serde(skip)]
+    pub value: Option<Vec<f64>>, // Controlled handle values, replaces 'start'
+    #[prop_or_default]
+    pub start: Vec<f64>, // Initial handle values when uncontrolled
     pub range: Range,
     pub connect: Option<Vec<bool>>,
+    #[serde(skip)]
+    pub segments: Option<Vec<Segment>>, // Per connect bar class, color and label
     pub step: Option<f64>,
     pub snap: Option<bool>,
     pub margin: Option<f64>,
@@ -70,6 +83,22 @@ pub struct Options {
     pub css_prefix: Option<String>,
     #[serde(rename = "cssClasses")]
     pub css_classes: Option<Vec<String>>,
+    // https://refreshless.com/nouislider/events-callbacks/
+    #[serde(skip)]
+    pub onupdate: Option<Callback<Event>>,
+    #[serde(skip)]
+    pub onslide: Option<Callback<Event>>,
+    #[serde(skip)]
+    pub onchange: Option<Callback<Event>>,
+    #[serde(skip)]
+    pub onset: Option<Callback<Event>>,
+    #[serde(skip)]
+    pub onstart: Option<Callback<Event>>,
+    #[serde(skip)]
+    pub onend: Option<Callback<Event>>,
+    // Requires the 'hover' behaviour, only 'unencoded' is set
+    #[serde(skip)]
+    pub onhover: Option<Callback<Event>>,
 }
 
 // https://refreshless.com/nouislider/events-callbacks/#section-binding
@@ -84,20 +113,11 @@ pub struct Event {
     pub pips: Vec<f64>,
 }
 
-pub struct Slider {
-    _callbacks: Vec<Callback>,
-    slider: no::NoUiSlider,
-    container: HtmlElement,
-    parent_state: Rc<SliderUpdateRef>,
-    _listener: ContextHandle<Rc<SliderUpdateRef>>,
-}
-
 pub type JsVec = Vec<JsValue>;
-pub type Callback = Closure<dyn Fn(JsVec, JsValue, JsVec, JsValue, JsVec, JsValue)>;
+pub type JsCallback = Closure<dyn Fn(JsVec, JsValue, JsVec, JsValue, JsVec, JsValue)>;
 
-pub enum Msg {
-    ContextChanged(Rc<SliderUpdateRef>),
-}
+// Slider events forwarded to the callback props
+const EVENTS: [&str; 6] = ["update", "slide", "change", "set", "start", "end"];
 
 fn get_pips(container: &HtmlElement) -> Vec<f64> {
     let pips_nodes = container.query_selector_all(".noUi-value").unwrap();
@@ -117,38 +137,102 @@ fn get_pips(container: &HtmlElement) -> Vec<f64> {
     pips
 }
 
-impl Component for Slider {
-    type Message = Msg;
-    type Properties = Options;
+// Number of handles, a controlled value replaces start
+fn handles(props: &SliderProps) -> usize {
+    props.value.as_ref().unwrap_or(&props.start).len()
+}
 
-    fn create(ctx: &Context<Self>) -> Self {
-        let container: Element = document().create_element("div").unwrap();
-        let container: HtmlElement = container.dyn_into().unwrap();
-        container.set_class_name("slider");
+// Options which noUiSlider can not change on an existing slider
+fn requires_rebuild(old: &SliderProps, new: &SliderProps) -> bool {
+    handles(old) != handles(new)
+        || old.connect != new.connect
+        || old.orientation != new.orientation
+        || old.direction != new.direction
+        || old.behaviour != new.behaviour
+        || old.handle_attributes != new.handle_attributes
+        || old.keyboard_support != new.keyboard_support
+        || old.keyboard_default_step != new.keyboard_default_step
+        || old.keyboard_page_multiplier != new.keyboard_page_multiplier
+        || old.keyboard_multiplier != new.keyboard_multiplier
+        || old.css_prefix != new.css_prefix
+        || old.css_classes != new.css_classes
+}
 
-        let serailizer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+// https://refreshless.com/nouislider/more/#section-update
+fn requires_update(old: &SliderProps, new: &SliderProps) -> bool {
+    old.range != new.range
+        || old.step != new.step
+        || old.snap != new.snap
+        || old.margin != new.margin
+        || old.limit != new.limit
+        || old.padding != new.padding
+        || old.animate != new.animate
+        || old.pips != new.pips
+        || old.tooltips != new.tooltips
+}
 
-        let slider = no::NoUiSlider::new(&container, &ctx.props().serialize(&serailizer).unwrap());
+// Serialize the props to noUiSlider options, a controlled value replaces start
+fn options(props: &SliderProps) -> JsValue {
+    let serailizer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    let options = props.serialize(&serailizer).unwrap();
+    if let Some(value) = &props.value {
+        let value = serde_wasm_bindgen::to_value(value).unwrap();
+        js_sys::Reflect::set(&options, &"start".into(), &value).unwrap();
+    }
+    options
+}
 
-        let (parent_state, _listener) = ctx
-            .link()
-            .context::<Rc<SliderUpdateRef>>(ctx.link().callback(Msg::ContextChanged))
-            .expect("context to be set");
+// Forward a slider event to the matching callback prop
+pub(crate) fn emit_event(props: &SliderProps, event_name: &str, event: Event) {
+    let callback = match event_name {
+        "update" => &props.onupdate,
+        "slide" => &props.onslide,
+        "change" => &props.onchange,
+        "set" => &props.onset,
+        "start" => &props.onstart,
+        "end" => &props.onend,
+        "hover" => &props.onhover,
+        _ => &None,
+    };
+    if let Some(callback) = callback {
+        callback.emit(event);
+    }
+}
+
+// A noUiSlider on a container element, together with the closures it calls.
+// Events are sent to 'events' along with their name.
+pub(crate) struct SliderInstance {
+    callbacks: Vec<JsCallback>,
+    hover_callback: Option<Closure<dyn Fn(JsValue)>>,
+    pub(crate) slider: no::NoUiSlider,
+    container: HtmlElement,
+    events: Callback<(&'static str, Event)>,
+    // Last controlled value set, with the handle values noUiSlider snapped it to
+    snapped: Option<(Vec<f64>, Vec<f64>)>,
+}
 
+impl SliderInstance {
+    pub(crate) fn new(
+        container: HtmlElement,
+        props: &SliderProps,
+        events: Callback<(&'static str, Event)>,
+    ) -> Self {
+        let slider = no::NoUiSlider::new(&container, &options(props));
         Self {
+            callbacks: vec![],
+            hover_callback: None,
             slider,
             container,
-            _callbacks: vec![],
-            parent_state,
-            _listener,
+            events,
+            snapped: None,
         }
     }
 
-    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
-        if first_render {
-            let parent_state = self.parent_state.clone();
+    pub(crate) fn bind_events(&mut self) {
+        for event_name in EVENTS {
+            let events = self.events.clone();
             let container = self.container.clone();
-            let callback = Callback::wrap(Box::new(
+            let callback = JsCallback::wrap(Box::new(
                 move |values: Vec<JsValue>,
                       handle: JsValue,
                       unencoded: Vec<JsValue>,
@@ -156,7 +240,7 @@ impl Component for Slider {
                       positions: Vec<JsValue>,
                       _nouislider: JsValue| {
                     // Construct event container
-                    let update = Event {
+                    let event = Event {
                         values: values.into_iter().map(|v| v.as_string().unwrap()).collect(),
                         handle: handle.as_f64().unwrap() as usize,
                         unencoded: unencoded.into_iter().map(|v| v.as_f64().unwrap()).collect(),
@@ -164,32 +248,88 @@ impl Component for Slider {
                         positions: positions.into_iter().map(|v| v.as_f64().unwrap()).collect(),
                         pips: get_pips(&container), // Get pips too
                     };
-                    // Forward slider update values to parent
-                    let cb = parent_state.update.reform(move |update| (update));
-                    cb.emit(update);
+                    events.emit((event_name, event));
                 },
             ));
             self.slider
-                .on("update", callback.as_ref().dyn_ref().unwrap());
-            self.slider.on("end", callback.as_ref().dyn_ref().unwrap());
-            self._callbacks.push(callback);
+                .on(event_name, callback.as_ref().dyn_ref().unwrap());
+            self.callbacks.push(callback);
         }
+        // The hover event only receives the value under the pointer
+        let events = self.events.clone();
+        let callback = Closure::<dyn Fn(JsValue)>::wrap(Box::new(move |value: JsValue| {
+            let event = Event {
+                unencoded: vec![value.as_f64().unwrap()],
+                ..Default::default()
+            };
+            events.emit(("hover", event));
+        }));
+        self.slider
+            .on("hover", callback.as_ref().dyn_ref().unwrap());
+        self.hover_callback = Some(callback);
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            Msg::ContextChanged(parent_state) => {
-                self.parent_state = parent_state;
-                true
+    // noUiSlider must not hold on to the closures once they are dropped
+    fn unbind_events(&mut self) {
+        for event_name in EVENTS {
+            self.slider.off(event_name);
+        }
+        self.slider.off("hover");
+        self.callbacks.clear();
+        self.hover_callback = None;
+    }
+
+    // Apply changed props, rebuilding the slider if noUiSlider can not update it
+    pub(crate) fn changed(&mut self, old: &SliderProps, new: &SliderProps) {
+        if requires_rebuild(old, new) {
+            let options = options(new);
+            // Uncontrolled handles stay where the user left them
+            let current = no::get_unencoded(&self.slider);
+            if new.value.is_none() && current.len() == new.start.len() {
+                let current = serde_wasm_bindgen::to_value(&current).unwrap();
+                js_sys::Reflect::set(&options, &"start".into(), &current).unwrap();
             }
+            self.unbind_events();
+            self.slider.destroy();
+            self.slider = no::NoUiSlider::new(&self.container, &options);
+            self.snapped = None;
+            self.bind_events();
+        } else if requires_update(old, new) {
+            // Passing start would reset the handles, a controlled value is set below
+            let options = options(new);
+            js_sys::Reflect::delete_property(
+                options.unchecked_ref::<js_sys::Object>(),
+                &"start".into(),
+            )
+            .unwrap();
+            self.slider.update_options(&options);
         }
+        // Also an unchanged value, the user may have moved the handles away from it
+        self.set_value(new);
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        // TODO: This may be done with https://refreshless.com/nouislider/number-formatting/
+    // Move the handles to the controlled value unless they are already there. A
+    // value off the step counts as there once noUiSlider has snapped it.
+    fn set_value(&mut self, props: &SliderProps) {
+        let Some(value) = &props.value else {
+            return;
+        };
+        let current = no::get_unencoded(&self.slider);
+        let snapped =
+            (self.snapped.as_ref()).is_some_and(|(set, to)| set == value && *to == current);
+        if current != *value && !snapped {
+            self.slider
+                .set(&serde_wasm_bindgen::to_value(value).unwrap());
+            self.snapped = Some((value.clone(), no::get_unencoded(&self.slider)));
+        }
+    }
+
+    // Overwrite the pips and tooltips text
+    // TODO: This may be done with https://refreshless.com/nouislider/number-formatting/
+    pub(crate) fn set_text(&self, values: &FormattedValues) {
         let pips = self.container.query_selector_all(".noUi-value").unwrap();
         for index in 0..pips.length() {
-            let opt_text = ctx.props().values.pips_text.get(index as usize);
+            let opt_text = values.pips_text.get(index as usize);
             if let Some(text) = opt_text {
                 pips.get(index)
                     .unwrap()
@@ -200,11 +340,175 @@ impl Component for Slider {
         }
         let tooltips = no::get_tooltips(&self.slider);
         for (index, tooltip) in tooltips.iter().enumerate() {
-            let opt_text = ctx.props().values.tooltips_text.get(index);
+            let opt_text = values.tooltips_text.get(index);
             if let Some(text) = opt_text {
                 tooltip.set_text_content(Some(text));
             }
         }
+    }
+
+    // Style the connect bars and center their labels between the handles
+    pub(crate) fn set_segments(&self, props: &SliderProps) {
+        let Some(base) = self.container.query_selector(".noUi-base").unwrap() else {
+            return;
+        };
+        // Labels are placed anew on every call
+        if let Some(labels) = base.query_selector(".noUi-connect-labels").unwrap() {
+            labels.remove();
+        }
+        // Bars are cleared of the previous segments, also when there are none now
+        let bars = self.container.query_selector_all(".noUi-connect").unwrap();
+        for bar in (0..bars.length()).filter_map(|i| bars.get(i)) {
+            let bar: HtmlElement = bar.dyn_into().unwrap();
+            if let Some(old) = bar.get_attribute("data-segment-class") {
+                for class in old.split_whitespace() {
+                    bar.class_list().remove_1(class).unwrap();
+                }
+                bar.remove_attribute("data-segment-class").unwrap();
+            }
+            if bar.has_attribute("data-segment-color") {
+                bar.style().remove_property("background").unwrap();
+                bar.remove_attribute("data-segment-color").unwrap();
+            }
+        }
+        let segments = props.segments.as_deref().unwrap_or_default();
+        let connect = props.connect.as_deref().unwrap_or_default();
+        if segments.is_empty() {
+            return;
+        }
+        let values = no::get_unencoded(&self.slider);
+        let positions: Vec<f64> =
+            serde_wasm_bindgen::from_value(self.slider.get_positions()).unwrap_or_default();
+        let end = |key: &str| (props.range.0.get(key)).and_then(|value| value.first().copied());
+        let (min, max) = (end("min").unwrap_or(0.0), end("max").unwrap_or(100.0));
+        // Labels are centered along the track and across it
+        let (side, across) = match props.orientation.as_deref() {
+            Some("vertical") => ("top", "left"),
+            _ => ("left", "top"),
+        };
+        let rtl = props.direction.as_deref() == Some("rtl");
+
+        let labels = document().create_element("div").unwrap();
+        labels.set_class_name("noUi-connect-labels");
+        // Bars exist for the connected segments only
+        let connected = connect
+            .iter()
+            .enumerate()
+            .filter(|(_, connected)| **connected);
+        for (bar, (i, _)) in connected.enumerate() {
+            let segment = segments.get(i).cloned().unwrap_or_default();
+            if let Some(bar) = bars.get(bar as u32) {
+                let bar: HtmlElement = bar.dyn_into().unwrap();
+                if let Some(class) = &segment.class {
+                    for class in class.split_whitespace() {
+                        bar.class_list().add_1(class).unwrap();
+                    }
+                    bar.set_attribute("data-segment-class", class).unwrap();
+                }
+                if let Some(color) = &segment.color {
+                    bar.style().set_property("background", color).unwrap();
+                    bar.set_attribute("data-segment-color", color).unwrap();
+                }
+            }
+            // Values and positions in percent at both ends of the segment, the ends
+            // of the track past the first and last handle
+            let at = |handle: usize, end: (f64, f64)| match values.get(handle) {
+                Some(value) => (*value, positions.get(handle).copied().unwrap_or(end.1)),
+                None => end,
+            };
+            let (from, from_position) = match i.checked_sub(1) {
+                Some(handle) => at(handle, (min, 0.0)),
+                None => (min, 0.0),
+            };
+            let (to, to_position) = at(i, (max, 100.0));
+            let text = match &segment.formatter {
+                Some(formatter) => Some(formatter.emit((from, to))),
+                None => segment.label.clone(),
+            };
+            let Some(text) = text else {
+                continue;
+            };
+            let center = (from_position + to_position) / 2.0;
+            let center = if rtl { 100.0 - center } else { center };
+            let label = document().create_element("div").unwrap();
+            label.set_class_name("noUi-connect-label");
+            label.set_text_content(Some(&text));
+            let style = format!(
+                "position: absolute; {}: {}%; {}: 50%; transform: translate(-50%, -50%); white-space: nowrap; pointer-events: none",
+                side, center, across
+            );
+            label.set_attribute("style", &style).unwrap();
+            labels.append_child(&label).unwrap();
+        }
+        base.append_child(&labels).unwrap();
+    }
+
+    pub(crate) fn destroy(&mut self) {
+        self.unbind_events();
+        self.slider.destroy();
+    }
+}
+
+pub struct Slider {
+    instance: SliderInstance,
+    container: HtmlElement,
+}
+
+pub enum Msg {
+    Event(&'static str, Event),
+}
+
+impl Component for Slider {
+    type Message = Msg;
+    type Properties = SliderProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let container: Element = document().create_element("div").unwrap();
+        let container: HtmlElement = container.dyn_into().unwrap();
+        container.set_class_name("slider");
+
+        let events = ctx
+            .link()
+            .callback(|(event_name, event)| Msg::Event(event_name, event));
+        let instance = SliderInstance::new(container.clone(), ctx.props(), events);
+
+        Self {
+            instance,
+            container,
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            self.instance.bind_events();
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        self.instance.changed(old_props, ctx.props());
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Event(event_name, event) => {
+                if event_name == "update" {
+                    self.instance.set_segments(ctx.props());
+                }
+                // Forward slider events to parent
+                emit_event(ctx.props(), event_name, event);
+                false
+            }
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        self.instance.destroy();
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        self.instance.set_text(&ctx.props().values);
+        self.instance.set_segments(ctx.props());
         let node: &Node = &self.container.clone().into();
         html! {
             {Html::VRef(node.clone())}
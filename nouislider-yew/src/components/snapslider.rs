@@ -0,0 +1,153 @@
+use std::rc::Rc;
+use yew::prelude::*;
+
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::data::data_points;
+use crate::data::snap_range;
+use crate::value::SliderValue;
+
+// A data point at a handle, 'index' is its position in the data
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataPoint<T: SliderValue> {
+    pub index: usize,
+    pub value: T,
+}
+
+// Callbacks receive the data points at the handles of the corresponding slider event
+#[derive(Properties, Clone, PartialEq)]
+pub struct SnapSliderProps<T: SliderValue> {
+    // Values the handles can rest on, in any order and with repeats
+    pub data: Vec<T>,
+    // Larger datasets are thinned out evenly to this many distinct values, at least 2
+    #[prop_or(1000)]
+    pub max_points: usize,
+    // Initial values at the handles, otherwise one handle at the smallest value
+    #[prop_or_default]
+    pub start: Vec<T>,
+    // Controlled values at the handles
+    pub value: Option<Vec<T>>,
+    pub onupdate: Option<Callback<Vec<DataPoint<T>>>>,
+    pub onslide: Option<Callback<Vec<DataPoint<T>>>>,
+    pub onchange: Option<Callback<Vec<DataPoint<T>>>>,
+    pub onset: Option<Callback<Vec<DataPoint<T>>>>,
+    pub onstart: Option<Callback<Vec<DataPoint<T>>>>,
+    pub onend: Option<Callback<Vec<DataPoint<T>>>>,
+}
+
+// A slider whose handles only rest on values present in a dataset, such as
+// release dates or measured samples. The track is SliderValue::to_f64 with a
+// snap range over the distinct values.
+pub struct SnapSlider<T: SliderValue> {
+    data: Rc<Vec<T>>,
+    // Distinct data values in track order with their index in the data
+    points: Rc<Vec<(usize, f64)>>,
+}
+
+fn points<T: SliderValue>(props: &SnapSliderProps<T>) -> Rc<Vec<(usize, f64)>> {
+    let values: Vec<f64> = props.data.iter().map(T::to_f64).collect();
+    Rc::new(data_points(&values, props.max_points))
+}
+
+// Data point nearest to a track position
+fn nearest(points: &[(usize, f64)], value: f64) -> (usize, f64) {
+    let next = points.partition_point(|(_, point)| *point < value);
+    match (next.checked_sub(1).map(|i| points[i]), points.get(next)) {
+        (Some(below), Some(above)) if value - below.1 <= above.1 - value => below,
+        (_, Some(above)) => *above,
+        (below, None) => below.unwrap_or((0, value)),
+    }
+}
+
+// Convert a data point callback into a typed event callback
+fn reform<T: SliderValue>(
+    data: &Rc<Vec<T>>,
+    points: &Rc<Vec<(usize, f64)>>,
+    callback: &Option<Callback<Vec<DataPoint<T>>>>,
+) -> Option<Callback<TypedEvent<f64>>> {
+    callback.as_ref().map(|cb| {
+        let (data, points) = (data.clone(), points.clone());
+        cb.reform(move |event: TypedEvent<f64>| {
+            (event.values.iter())
+                .map(|value| {
+                    let (index, _) = nearest(&points, *value);
+                    DataPoint {
+                        index,
+                        value: data[index].clone(),
+                    }
+                })
+                .collect()
+        })
+    })
+}
+
+impl<T: SliderValue> Component for SnapSlider<T> {
+    type Message = ();
+    type Properties = SnapSliderProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            data: Rc::new(ctx.props().data.clone()),
+            points: points(ctx.props()),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.data != old_props.data || props.max_points != old_props.max_points {
+            self.data = Rc::new(props.data.clone());
+            self.points = points(props);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let points = &self.points;
+        // noUiSlider needs a range of at least two values
+        if points.len() < 2 {
+            return html! {};
+        }
+        let (min, max) = (points[0].1, points[points.len() - 1].1);
+        let snapped = |values: &Vec<T>| -> Vec<f64> {
+            (values.iter())
+                .map(|value| nearest(points, value.to_f64()).1)
+                .collect()
+        };
+        let mut start = snapped(&props.start);
+        if start.is_empty() {
+            start.push(min);
+        }
+        let value = props.value.as_ref().map(snapped);
+        let values: Vec<f64> = points.iter().map(|(_, value)| *value).collect();
+        let options = SliderProps {
+            range: snap_range(&values),
+            snap: Some(true),
+            tooltips: Some(true),
+            ..Default::default()
+        };
+        let data = &self.data;
+        let formatter = {
+            let (data, points) = (data.clone(), points.clone());
+            Callback::from(move |value| data[nearest(&points, value).0].format())
+        };
+
+        html! {
+            <TypedSlider<f64>
+                {min}
+                {max}
+                {start}
+                {value}
+                {options}
+                {formatter}
+                onupdate={reform(data, points, &props.onupdate)}
+                onslide={reform(data, points, &props.onslide)}
+                onchange={reform(data, points, &props.onchange)}
+                onset={reform(data, points, &props.onset)}
+                onstart={reform(data, points, &props.onstart)}
+                onend={reform(data, points, &props.onend)}
+            />
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use yew::prelude::*;
+
+use super::nouislider::HandleAttributes;
+use super::nouislider::Pips;
+use super::nouislider::SliderProps;
+use super::typedslider::TypedEvent;
+use super::typedslider::TypedSlider;
+use crate::value::SliderValue;
+
+const DAY: f64 = 86400.0;
+
+// Callbacks receive the start and end time of the selection
+#[derive(Properties, Clone, PartialEq)]
+pub struct TimeOfDaySliderProps<T: SliderValue> {
+    // Initial start and end time, otherwise 09:00 to 17:00
+    pub start: Option<Vec<T>>,
+    // Controlled start and end time
+    pub value: Option<Vec<T>>,
+    #[prop_or(15)]
+    pub step_minutes: u32,
+    // Show times as 10:30 PM instead of 22:30
+    #[prop_or_default]
+    pub hour12: bool,
+    // Allow selections across midnight, a start after the end such as 22:00 to
+    // 06:00 selects the time from the start through midnight to the end. A button
+    // below the slider flips the selection between the inner and outer parts of
+    // the track, since the handles can not pass each other.
+    #[prop_or_default]
+    pub wrap: bool,
+    pub onupdate: Option<Callback<Vec<T>>>,
+    pub onslide: Option<Callback<Vec<T>>>,
+    pub onchange: Option<Callback<Vec<T>>>,
+    pub onset: Option<Callback<Vec<T>>>,
+    pub onstart: Option<Callback<Vec<T>>>,
+    pub onend: Option<Callback<Vec<T>>>,
+}
+
+// Time of day 'seconds' after midnight, the end of the track shows as 24:00
+pub fn format_time_of_day(seconds: f64, hour12: bool) -> String {
+    let minutes = (seconds / 60.0).round() as u32;
+    let (hour, minute) = (minutes / 60, minutes % 60);
+    match hour12 {
+        true => {
+            let suffix = if hour % 24 < 12 { "AM" } else { "PM" };
+            format!("{}:{:02} {}", (hour + 11) % 12 + 1, minute, suffix)
+        }
+        false => format!("{:02}:{:02}", hour, minute),
+    }
+}
+
+// Time 'seconds' after midnight, the end of the track is the last second of the
+// day rather than the next midnight
+pub fn time_of_day<T: SliderValue>(seconds: f64) -> T {
+    T::from_f64(seconds.min(DAY - 1.0))
+}
+
+// A slider for chrono::NaiveTime or time::Time with two handles. Handles stay in
+// order on the track, a selection across midnight highlights the outer parts of
+// the track instead, and events report its start and end swapped back.
+pub struct TimeOfDaySlider<T: SliderValue> {
+    // Whether the selection runs across midnight, kept while the handles meet
+    wrapped: bool,
+    // Last handle times in track order
+    handles: Vec<f64>,
+    _time: PhantomData<T>,
+}
+
+pub enum Msg {
+    Update(TypedEvent<f64>),
+    Flip,
+}
+
+fn seconds<T: SliderValue>(times: &[T]) -> Vec<f64> {
+    times.iter().map(T::to_f64).collect()
+}
+
+fn start<T: SliderValue>(props: &TimeOfDaySliderProps<T>) -> Vec<f64> {
+    (props.start.as_deref())
+        .map(seconds)
+        .unwrap_or_else(|| vec![9.0 * 3600.0, 17.0 * 3600.0])
+}
+
+// Wrap state of the selection given by the props, a start equal to the end
+// keeps the 'previous' state
+fn wrapped<T: SliderValue>(props: &TimeOfDaySliderProps<T>, previous: bool) -> bool {
+    let selection = props
+        .value
+        .as_deref()
+        .map(seconds)
+        .unwrap_or_else(|| start(props));
+    match selection[..] {
+        [start, end] if props.wrap && start != end => start > end,
+        [_, _] if props.wrap => previous,
+        _ => false,
+    }
+}
+
+// Handles in track order
+fn sorted(mut seconds: Vec<f64>) -> Vec<f64> {
+    seconds.sort_by(f64::total_cmp);
+    seconds
+}
+
+// Start and end time of handles at 'seconds'
+fn times<T: SliderValue>(wrapped: bool, seconds: &[f64]) -> Vec<T> {
+    let mut values: Vec<T> = seconds.iter().map(|v| time_of_day(*v)).collect();
+    if wrapped {
+        values.reverse();
+    }
+    values
+}
+
+// Convert a time callback into a typed event callback
+fn reform<T: SliderValue>(
+    wrapped: bool,
+    callback: &Option<Callback<Vec<T>>>,
+) -> Option<Callback<TypedEvent<f64>>> {
+    callback
+        .as_ref()
+        .map(|cb| cb.reform(move |event: TypedEvent<f64>| times(wrapped, &event.values)))
+}
+
+impl<T: SliderValue> Component for TimeOfDaySlider<T> {
+    type Message = Msg;
+    type Properties = TimeOfDaySliderProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let handles = props.value.as_deref().map(seconds);
+        Self {
+            wrapped: wrapped(props, false),
+            handles: sorted(handles.unwrap_or_else(|| start(props))),
+            _time: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Update(event) => {
+                self.handles = event.values.clone();
+                if let Some(onupdate) = reform(self.wrapped, &props.onupdate) {
+                    onupdate.emit(event);
+                }
+                false
+            }
+            // The handles stay, their times swap between start and end
+            Msg::Flip => {
+                self.wrapped = !self.wrapped;
+                let values = times(self.wrapped, &self.handles);
+                let callbacks = [&props.onupdate, &props.onchange, &props.onset];
+                for callback in callbacks.into_iter().flatten() {
+                    callback.emit(values.clone());
+                }
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value || props.wrap != old_props.wrap {
+            self.wrapped = wrapped(props, self.wrapped);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let value = props.value.as_deref().map(seconds);
+        let start = start(props);
+        let wrapped = self.wrapped;
+        let labels = match wrapped {
+            true => ["end", "start"],
+            false => ["start", "end"],
+        };
+        let handle_attributes = (labels.iter())
+            .map(|label| HashMap::from([("aria-label".to_string(), label.to_string())]))
+            .collect();
+        let options = SliderProps {
+            step: Some(props.step_minutes as f64 * 60.0),
+            connect: Some(vec![wrapped, !wrapped, wrapped]),
+            tooltips: Some(true),
+            handle_attributes: Some(HandleAttributes(handle_attributes)),
+            pips: Some(Pips {
+                mode: "values".to_string(),
+                values: Some((0..=8).map(|i| i as f64 * DAY / 8.0).collect()),
+                density: Some(100.0 / 24.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let formatter = {
+            let hour12 = props.hour12;
+            Callback::from(move |seconds| format_time_of_day(seconds, hour12))
+        };
+
+        html! {
+            <>
+            <TypedSlider<f64>
+                min={0.0}
+                max={DAY}
+                start={sorted(start)}
+                value={value.map(sorted)}
+                {options}
+                {formatter}
+                onupdate={ctx.link().callback(Msg::Update)}
+                onslide={reform(wrapped, &props.onslide)}
+                onchange={reform(wrapped, &props.onchange)}
+                onset={reform(wrapped, &props.onset)}
+                onstart={reform(wrapped, &props.onstart)}
+                onend={reform(wrapped, &props.onend)}
+            />
+            if props.wrap {
+                <button
+                    type="button"
+                    class="time-of-day-wrap"
+                    aria-pressed={wrapped.to_string()}
+                    onclick={ctx.link().callback(|_| Msg::Flip)}
+                >
+                    {"Across midnight"}
+                </button>
+            }
+            </>
+        }
+    }
+}
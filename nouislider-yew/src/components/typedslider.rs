@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use yew::prelude::*;
+
+use super::nouislider::Event as SliderEvent;
+use super::nouislider::FormattedValues;
+use super::nouislider::Pips;
+use super::nouislider::Range;
+use super::nouislider::Segment;
+use super::nouislider::Slider;
+use super::nouislider::SliderProps;
+use crate::value::SliderValue;
+
+// A slider event with the handle values converted to T
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedEvent<T: SliderValue> {
+    pub values: Vec<T>,
+    pub handle: usize,
+    pub tap: bool,
+    pub positions: Vec<f64>,
+}
+
+// Maps SliderValue::to_f64 values onto the noUiSlider track and back
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackMapping {
+    pub to_track: Callback<f64, f64>,
+    pub from_track: Callback<f64, f64>,
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct TypedSliderProps<T: SliderValue> {
+    pub min: T,
+    pub max: T,
+    #[prop_or_default]
+    pub start: Vec<T>,
+    pub value: Option<Vec<T>>,
+    // Remaining slider options in track units, a non-empty range replaces min and
+    // max. Pip values are SliderValue::to_f64 values, placed like the handles, and
+    // segment formatters receive SliderValue::to_f64 values as well.
+    #[prop_or_default]
+    pub options: SliderProps,
+    // Track positions of values, the track is SliderValue::to_f64 if not set
+    pub mapping: Option<TrackMapping>,
+    // Tooltip and pip text, defaults to SliderValue::format
+    pub formatter: Option<Callback<T, String>>,
+    // Pip text, defaults to 'formatter'
+    pub pip_formatter: Option<Callback<T, String>>,
+    pub onupdate: Option<Callback<TypedEvent<T>>>,
+    pub onslide: Option<Callback<TypedEvent<T>>>,
+    pub onchange: Option<Callback<TypedEvent<T>>>,
+    pub onset: Option<Callback<TypedEvent<T>>>,
+    pub onstart: Option<Callback<TypedEvent<T>>>,
+    pub onend: Option<Callback<TypedEvent<T>>>,
+}
+
+pub enum Msg {
+    SliderUpdate(SliderEvent),
+}
+
+pub struct TypedSlider<T: SliderValue> {
+    slider_values: FormattedValues,
+    _value: PhantomData<T>,
+}
+
+fn track(mapping: &Option<TrackMapping>, value: f64) -> f64 {
+    match mapping {
+        Some(mapping) => mapping.to_track.emit(value),
+        None => value,
+    }
+}
+
+// Inverse of track
+fn untrack(mapping: &Option<TrackMapping>, track: f64) -> f64 {
+    match mapping {
+        Some(mapping) => mapping.from_track.emit(track),
+        None => track,
+    }
+}
+
+fn to_track<T: SliderValue>(mapping: &Option<TrackMapping>, value: &T) -> f64 {
+    track(mapping, value.to_f64())
+}
+
+// Value at a track position, like 'min' such as in the offset of a date
+fn from_track<T: SliderValue>(mapping: &Option<TrackMapping>, min: &T, value: f64) -> T {
+    T::from_f64_like(untrack(mapping, value), min)
+}
+
+fn format<T: SliderValue>(
+    mapping: &Option<TrackMapping>,
+    min: &T,
+    formatter: Option<&Callback<T, String>>,
+    value: f64,
+) -> String {
+    let value: T = from_track(mapping, min, value);
+    match formatter {
+        Some(formatter) => formatter.emit(value),
+        None => value.format(),
+    }
+}
+
+fn typed_event<T: SliderValue>(
+    mapping: &Option<TrackMapping>,
+    min: &T,
+    event: SliderEvent,
+) -> TypedEvent<T> {
+    TypedEvent {
+        values: (event.unencoded.into_iter())
+            .map(|v| from_track(mapping, min, v))
+            .collect(),
+        handle: event.handle,
+        tap: event.tap,
+        positions: event.positions,
+    }
+}
+
+// Convert a typed event callback into a slider event callback
+fn reform<T: SliderValue>(
+    mapping: &Option<TrackMapping>,
+    min: &T,
+    callback: &Option<Callback<TypedEvent<T>>>,
+) -> Option<Callback<SliderEvent>> {
+    callback.as_ref().map(|cb| {
+        let (mapping, min) = (mapping.clone(), min.clone());
+        cb.reform(move |event| typed_event(&mapping, &min, event))
+    })
+}
+
+impl<T: SliderValue> Component for TypedSlider<T> {
+    type Message = Msg;
+    type Properties = TypedSliderProps<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            slider_values: FormattedValues::default(),
+            _value: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SliderUpdate(event) => {
+                let props = ctx.props();
+                let mapping = &props.mapping;
+                let pip_formatter = props.pip_formatter.as_ref().or(props.formatter.as_ref());
+                self.slider_values = FormattedValues {
+                    tooltips_text: (event.unencoded.iter())
+                        .map(|v| format(mapping, &props.min, props.formatter.as_ref(), *v))
+                        .collect(),
+                    pips_text: (event.pips.iter())
+                        .map(|v| format(mapping, &props.min, pip_formatter, *v))
+                        .collect(),
+                };
+                // Send values to parent component
+                if let Some(onupdate) = &props.onupdate {
+                    onupdate.emit(typed_event(mapping, &props.min, event));
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let mapping = &props.mapping;
+        let range = match props.options.range.0.is_empty() {
+            true => Range(HashMap::from([
+                ("min".to_string(), vec![to_track(mapping, &props.min)]),
+                ("max".to_string(), vec![to_track(mapping, &props.max)]),
+            ])),
+            false => props.options.range.clone(),
+        };
+        let to_f64 = |values: &[T]| values.iter().map(|v| to_track(mapping, v)).collect();
+        // Only the "values" mode holds values, count and positions are left as is
+        let pips = props
+            .options
+            .pips
+            .clone()
+            .map(|pips| match pips.mode.as_str() {
+                "values" => Pips {
+                    values: (pips.values.as_ref())
+                        .map(|values| values.iter().map(|v| track(mapping, *v)).collect()),
+                    ..pips
+                },
+                _ => pips,
+            });
+        let segments = props.options.segments.clone().map(|segments| {
+            (segments.into_iter())
+                .map(|segment| Segment {
+                    formatter: segment.formatter.map(|formatter| {
+                        let mapping = mapping.clone();
+                        formatter.reform(move |(from, to)| {
+                            (untrack(&mapping, from), untrack(&mapping, to))
+                        })
+                    }),
+                    ..segment
+                })
+                .collect()
+        });
+        let options = SliderProps {
+            values: self.slider_values.clone(),
+            value: props.value.as_deref().map(to_f64),
+            start: to_f64(&props.start),
+            range,
+            pips,
+            segments,
+            onupdate: Some(ctx.link().callback(Msg::SliderUpdate)),
+            onslide: reform(mapping, &props.min, &props.onslide),
+            onchange: reform(mapping, &props.min, &props.onchange),
+            onset: reform(mapping, &props.min, &props.onset),
+            onstart: reform(mapping, &props.min, &props.onstart),
+            onend: reform(mapping, &props.min, &props.onend),
+            ..props.options.clone()
+        };
+        html! {
+            <Slider ..options />
+        }
+    }
+}
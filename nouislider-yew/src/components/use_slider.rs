@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use super::nouislider::emit_event;
+use super::nouislider::Event;
+use super::nouislider::SliderInstance;
+use super::nouislider::SliderProps;
+
+// Returned by use_slider, attach 'node_ref' to the element which should hold the slider
+#[derive(Clone)]
+pub struct UseSliderHandle {
+    pub node_ref: NodeRef,
+    pub values: Vec<f64>, // Unencoded handle values from the latest update event
+    instance: Rc<RefCell<Option<SliderInstance>>>,
+}
+
+impl UseSliderHandle {
+    pub fn set(&self, values: &[f64]) {
+        if let Some(instance) = self.instance.borrow().as_ref() {
+            instance
+                .slider
+                .set(&serde_wasm_bindgen::to_value(values).unwrap());
+        }
+    }
+
+    pub fn reset(&self) {
+        if let Some(instance) = self.instance.borrow().as_ref() {
+            instance.slider.reset();
+        }
+    }
+
+    pub fn disable(&self) {
+        if let Some(instance) = self.instance.borrow().as_ref() {
+            instance.slider.disable();
+        }
+    }
+
+    pub fn enable(&self) {
+        if let Some(instance) = self.instance.borrow().as_ref() {
+            instance.slider.enable();
+        }
+    }
+}
+
+// Create a slider on the element referenced by the returned handle. Changed
+// options are applied like props of the Slider component.
+#[hook]
+pub fn use_slider(options: SliderProps) -> UseSliderHandle {
+    let node_ref = use_node_ref();
+    let values = use_state(Vec::new);
+    let instance = use_mut_ref(|| None::<SliderInstance>);
+    // The options last applied to the slider, also used to forward events
+    let applied = use_mut_ref(|| options.clone());
+
+    {
+        let node_ref = node_ref.clone();
+        let values = values.clone();
+        let instance = instance.clone();
+        let applied = applied.clone();
+        use_effect_with_deps(
+            move |_| {
+                let container = node_ref.cast::<HtmlElement>().unwrap();
+                let events = {
+                    let applied = applied.clone();
+                    let instance = instance.clone();
+                    Callback::from(move |(event_name, event): (&'static str, Event)| {
+                        if event_name == "update" {
+                            values.set(event.unencoded.clone());
+                            // The slider is borrowed while it applies changed options
+                            if let Ok(Some(slider)) = instance.try_borrow().as_deref() {
+                                slider.set_segments(&applied.borrow());
+                            }
+                        }
+                        emit_event(&applied.borrow(), event_name, event);
+                    })
+                };
+                let mut slider = SliderInstance::new(container, &applied.borrow(), events);
+                slider.bind_events();
+                *instance.borrow_mut() = Some(slider);
+                move || {
+                    if let Some(mut slider) = instance.borrow_mut().take() {
+                        slider.destroy();
+                    }
+                }
+            },
+            (),
+        );
+    }
+    {
+        let instance = instance.clone();
+        use_effect_with_deps(
+            move |options| {
+                let old = applied.replace(options.clone());
+                if let Some(slider) = instance.borrow_mut().as_mut() {
+                    slider.changed(&old, options);
+                    slider.set_text(&options.values);
+                    slider.set_segments(options);
+                }
+            },
+            options,
+        );
+    }
+
+    UseSliderHandle {
+        node_ref,
+        values: (*values).clone(),
+        instance,
+    }
+}
+
+// Function component version of Slider
+#[function_component]
+pub fn SliderFn(props: &SliderProps) -> Html {
+    let slider = use_slider(props.clone());
+    html! {
+        <div class="slider" ref={slider.node_ref} />
+    }
+}
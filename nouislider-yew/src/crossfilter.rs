@@ -0,0 +1,162 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::data::Bins;
+use crate::data::Histogram;
+use crate::data::RangeIndex;
+
+// Position of a row on the track of a dimension, compared by pointer like yew callbacks
+pub struct RowKey<R>(Rc<dyn Fn(&R) -> f64>);
+
+impl<R, F: Fn(&R) -> f64 + 'static> From<F> for RowKey<R> {
+    fn from(key: F) -> Self {
+        Self(Rc::new(key))
+    }
+}
+
+impl<R> Clone for RowKey<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R> PartialEq for RowKey<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+struct Dimension<R> {
+    name: String,
+    key: RowKey<R>,
+    // Key of every row
+    keys: Vec<f64>,
+    // Rows sorted by key
+    index: RangeIndex<usize>,
+    filter: Option<(f64, f64)>,
+    // Positions in 'index' of the rows inside the filter
+    selected: Range<usize>,
+}
+
+// Rows filtered by ranges over several dimensions, such as price, date and
+// rating. Filter changes only visit the rows entering or leaving the filter.
+pub struct Crossfilter<R> {
+    rows: Rc<Vec<R>>,
+    dimensions: Vec<Dimension<R>>,
+    // Per row, a bit for each dimension whose filter leaves the row out
+    masks: Vec<u64>,
+}
+
+impl<R> Crossfilter<R> {
+    pub fn new(rows: Rc<Vec<R>>) -> Self {
+        let masks = vec![0; rows.len()];
+        Self {
+            rows,
+            dimensions: vec![],
+            masks,
+        }
+    }
+
+    pub fn rows(&self) -> &Rc<Vec<R>> {
+        &self.rows
+    }
+
+    // Replace the rows, keeping the dimensions and their filters
+    pub fn set_rows(&mut self, rows: Rc<Vec<R>>) {
+        let dimensions = std::mem::take(&mut self.dimensions);
+        *self = Self::new(rows);
+        for dimension in dimensions {
+            let id = self.dimension(&dimension.name, dimension.key);
+            self.filter(id, dimension.filter);
+        }
+    }
+
+    // Id of the dimension called 'name', added with 'key' unless it exists. There
+    // can be up to 64 dimensions.
+    pub fn dimension(&mut self, name: &str, key: RowKey<R>) -> usize {
+        if let Some(id) = self.find(name) {
+            return id;
+        }
+        assert!(
+            self.dimensions.len() < 64,
+            "too many crossfilter dimensions"
+        );
+        let keys: Vec<f64> = self.rows.iter().map(|row| (key.0)(row)).collect();
+        let index = RangeIndex::new(0..self.rows.len(), |row| keys[*row]);
+        self.dimensions.push(Dimension {
+            name: name.to_string(),
+            key,
+            keys,
+            index,
+            filter: None,
+            selected: 0..self.rows.len(),
+        });
+        self.dimensions.len() - 1
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        (self.dimensions.iter()).position(|dimension| dimension.name == name)
+    }
+
+    // Smallest and largest key of a dimension
+    pub fn extent(&self, id: usize) -> Option<(f64, f64)> {
+        let dimension = &self.dimensions[id];
+        let first = dimension.index.items().first()?;
+        let last = dimension.index.items().last()?;
+        Some((dimension.keys[*first], dimension.keys[*last]))
+    }
+
+    pub fn filter_range(&self, id: usize) -> Option<(f64, f64)> {
+        self.dimensions[id].filter
+    }
+
+    // Keep the rows with keys from 'low' to 'high' in a dimension, or all rows with None
+    pub fn filter(&mut self, id: usize, range: Option<(f64, f64)>) {
+        let dimension = &mut self.dimensions[id];
+        let old = dimension.selected.clone();
+        let new = match range {
+            Some((low, high)) => dimension.index.positions(low, high),
+            None => 0..dimension.index.len(),
+        };
+        let bit = 1 << id;
+        // Rows change only between the old and the new start, and the old and the new end
+        let changes = [
+            old.start.min(new.start)..old.start.max(new.start),
+            old.end.min(new.end)..old.end.max(new.end),
+        ];
+        for position in changes.into_iter().flatten() {
+            let row = dimension.index.items()[position];
+            match new.contains(&position) {
+                true => self.masks[row] &= !bit,
+                false => self.masks[row] |= bit,
+            }
+        }
+        dimension.filter = range;
+        dimension.selected = new;
+    }
+
+    // Indices of the rows inside all filters
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.masks.iter().enumerate())
+            .filter(|(_, mask)| **mask == 0)
+            .map(|(row, _)| row)
+    }
+
+    pub fn selected_rows(&self) -> impl Iterator<Item = &R> + '_ {
+        self.selected().map(|row| &self.rows[row])
+    }
+
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.masks[row] == 0
+    }
+
+    // Histogram of a dimension over the rows inside the filters of all other dimensions
+    pub fn histogram(&self, id: usize, bins: Bins, min: f64, max: f64) -> Histogram {
+        let others = !(1 << id);
+        let keys = &self.dimensions[id].keys;
+        let values = (self.masks.iter().zip(keys))
+            .filter(|(mask, _)| **mask & others == 0)
+            .map(|(_, key)| key);
+        Histogram::from_values(bins, min, max, values)
+    }
+}
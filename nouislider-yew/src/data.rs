@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::components::nouislider::Range;
+use crate::value::SliderValue;
+
+// Value below which a share 'q' of 'sorted' lies, interpolating between
+// neighbours. An empty 'sorted' gives NaN.
+pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    let position = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let (lower, fraction) = (position.floor() as usize, position.fract());
+    match sorted.get(lower + 1) {
+        Some(upper) => sorted[lower] + (upper - sorted[lower]) * fraction,
+        None => sorted[lower],
+    }
+}
+
+// A range from the first to the last of 'sorted' values with 'slices' equal
+// slices of track, each holding an equal share of the values. Breakpoints which
+// would not increase, such as in runs of equal values, are left out.
+pub fn quantile_range(sorted: &[f64], slices: usize) -> Range {
+    let (Some(min), Some(max)) = (sorted.first(), sorted.last()) else {
+        return Range::default();
+    };
+    let mut range = HashMap::from([
+        ("min".to_string(), vec![*min]),
+        ("max".to_string(), vec![*max]),
+    ]);
+    let mut previous = *min;
+    for i in 1..slices {
+        let q = i as f64 / slices as f64;
+        let value = quantile(sorted, q);
+        if previous < value && value < *max {
+            range.insert(format!("{}%", q * 100.0), vec![value]);
+            previous = value;
+        }
+    }
+    Range(range)
+}
+
+// Distinct values of 'data' in order, with the index of their first occurrence.
+// More than 'max_points' values, counted as at least two, are thinned out evenly,
+// keeping the first and last.
+pub fn data_points(data: &[f64], max_points: usize) -> Vec<(usize, f64)> {
+    let max_points = max_points.max(2);
+    let mut points: Vec<(usize, f64)> = data.iter().copied().enumerate().collect();
+    points.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    points.dedup_by(|b, a| a.1 == b.1);
+    if points.len() > max_points {
+        let last = (points.len() - 1) as f64;
+        points = (0..max_points)
+            .map(|i| points[(i as f64 * last / (max_points - 1) as f64).round() as usize])
+            .collect();
+    }
+    points
+}
+
+// A range for noUiSlider's snap option where handles only rest on 'values',
+// which must be sorted and distinct. A single value gives a range of only min
+// and max.
+pub fn snap_range(values: &[f64]) -> Range {
+    let (Some(min), Some(max)) = (values.first(), values.last()) else {
+        return Range::default();
+    };
+    let mut range = HashMap::from([
+        ("min".to_string(), vec![*min]),
+        ("max".to_string(), vec![*max]),
+    ]);
+    if values.len() < 2 || min == max {
+        return Range(range);
+    }
+    for value in &values[1..values.len() - 1] {
+        let percent = (value - min) / (max - min) * 100.0;
+        range.insert(format!("{}%", percent), vec![*value]);
+    }
+    Range(range)
+}
+
+// How a range is divided into histogram bins
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bins {
+    // Bins of equal width from the lower to the upper end
+    Count(usize),
+    // Bins of a fixed width starting at the lower end, the last bin ends at the upper end
+    Width(f64),
+}
+
+impl Bins {
+    // Edges of the bins from 'min' to 'max'
+    pub fn edges(&self, min: f64, max: f64) -> Vec<f64> {
+        let count = match *self {
+            Bins::Count(count) => count.max(1),
+            Bins::Width(width) if width > 0.0 => {
+                ((max - min) / width - 1e-9).ceil().max(1.0) as usize
+            }
+            Bins::Width(_) => 1,
+        };
+        let mut edges: Vec<f64> = (0..count)
+            .map(|i| match *self {
+                Bins::Width(width) if width > 0.0 => min + i as f64 * width,
+                _ => min + (max - min) * i as f64 / count as f64,
+            })
+            .collect();
+        edges.push(max);
+        edges
+    }
+}
+
+// Counts of values in consecutive bins. Bin i holds the values from edges[i] up
+// to edges[i + 1], the last bin also holds its upper edge.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram {
+    pub edges: Vec<f64>,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    // An empty histogram over 'min' to 'max'
+    pub fn new(bins: Bins, min: f64, max: f64) -> Self {
+        let edges = bins.edges(min, max);
+        let counts = vec![0; edges.len() - 1];
+        Self { edges, counts }
+    }
+
+    pub fn from_values<'a>(
+        bins: Bins,
+        min: f64,
+        max: f64,
+        values: impl IntoIterator<Item = &'a f64>,
+    ) -> Self {
+        let mut histogram = Self::new(bins, min, max);
+        for value in values {
+            histogram.add(*value);
+        }
+        histogram
+    }
+
+    // Bin holding 'value', if it is in range
+    pub fn bin(&self, value: f64) -> Option<usize> {
+        let (Some(min), Some(max)) = (self.edges.first(), self.edges.last()) else {
+            return None;
+        };
+        if !(*min <= value && value <= *max) {
+            return None;
+        }
+        let bin = self.edges.partition_point(|edge| *edge <= value) - 1;
+        Some(bin.min(self.counts.len() - 1))
+    }
+
+    // Count 'value', values out of range are left out
+    pub fn add(&mut self, value: f64) {
+        if let Some(bin) = self.bin(value) {
+            self.counts[bin] += 1;
+        }
+    }
+
+    pub fn max_count(&self) -> usize {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+}
+
+// Items sorted by a key on the slider track, for counting and listing the items
+// between handles on every slider event without a pass over all items
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RangeIndex<T> {
+    keys: Vec<f64>,
+    items: Vec<T>,
+}
+
+impl<T> RangeIndex<T> {
+    pub fn new(items: impl IntoIterator<Item = T>, key: impl Fn(&T) -> f64) -> Self {
+        let mut pairs: Vec<(f64, T)> = items.into_iter().map(|item| (key(&item), item)).collect();
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (keys, items) = pairs.into_iter().unzip();
+        Self { keys, items }
+    }
+
+    // Add an item after the items with the same key
+    pub fn insert(&mut self, key: f64, item: T) {
+        let position = self.keys.partition_point(|k| k.total_cmp(&key).is_le());
+        self.keys.insert(position, key);
+        self.items.insert(position, item);
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    // All items in key order
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    // Positions of the items with keys from 'low' to 'high', both included
+    pub fn positions(&self, low: f64, high: f64) -> std::ops::Range<usize> {
+        let start = self.keys.partition_point(|key| *key < low);
+        let end = self.keys.partition_point(|key| *key <= high);
+        start..end.max(start)
+    }
+
+    // Items with keys from 'low' to 'high' in key order
+    pub fn range(&self, low: f64, high: f64) -> &[T] {
+        &self.items[self.positions(low, high)]
+    }
+
+    pub fn count(&self, low: f64, high: f64) -> usize {
+        self.positions(low, high).len()
+    }
+
+    // Items selected by handles at 'values', between the first and the last
+    // handle or up to a single handle, as with a connected slider
+    pub fn selected(&self, values: &[f64]) -> &[T] {
+        let high = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let low = match values.len() {
+            1 => f64::NEG_INFINITY,
+            _ => values.iter().copied().fold(f64::INFINITY, f64::min),
+        };
+        self.range(low, high)
+    }
+}
+
+impl<T: SliderValue> RangeIndex<T> {
+    // Values keyed by SliderValue::to_f64
+    pub fn from_values(values: impl IntoIterator<Item = T>) -> Self {
+        Self::new(values, T::to_f64)
+    }
+}
@@ -0,0 +1,270 @@
+// Local date and time fields without a time zone, months and days count from 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalDateTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let next = match month {
+        12 => LocalDateTime::date(year + 1, 1, 1),
+        _ => LocalDateTime::date(year, month + 1, 1),
+    };
+    (next.days() - LocalDateTime::date(year, month, 1).days()) as u32
+}
+
+impl LocalDateTime {
+    pub fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    // Midnight at the start of a day
+    pub fn date(year: i32, month: u32, day: u32) -> Self {
+        Self::new(year, month, day, 0, 0, 0)
+    }
+
+    // Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    fn days(&self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    // Inverse of days
+    fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month + 2) / 5 + 1) as u32;
+        let month = if month < 10 { month + 3 } else { month - 9 } as u32;
+        let year = (year_of_era + era * 400 + (month <= 2) as i64) as i32;
+        Self::date(year, month, day)
+    }
+
+    // Seconds since 1970-01-01 00:00, as if the local time was UTC
+    pub fn seconds(&self) -> i64 {
+        self.days() * 86400 + (self.hour * 3600 + self.minute * 60 + self.second) as i64
+    }
+
+    // Inverse of seconds
+    pub fn from_seconds(seconds: i64) -> Self {
+        let time = seconds.rem_euclid(86400) as u32;
+        Self {
+            hour: time / 3600,
+            minute: time / 60 % 60,
+            second: time % 60,
+            ..Self::from_days(seconds.div_euclid(86400))
+        }
+    }
+
+    // Days since Monday
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.days() + 3).rem_euclid(7) as u32
+    }
+
+    // Days since January 1st
+    pub fn ordinal0(&self) -> u32 {
+        (self.days() - Self::date(self.year, 1, 1).days()) as u32
+    }
+
+    pub fn is_midnight(&self) -> bool {
+        (self.hour, self.minute, self.second) == (0, 0, 0)
+    }
+
+    pub fn add_seconds(self, seconds: i64) -> Self {
+        Self::from_seconds(self.seconds() + seconds)
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        self.add_seconds(days * 86400)
+    }
+
+    // Add calendar months, the day is clamped to the length of the month
+    pub fn add_months(self, months: u32) -> Self {
+        let month0 = self.year as i64 * 12 + self.month as i64 - 1 + months as i64;
+        let (year, month) = (
+            month0.div_euclid(12) as i32,
+            month0.rem_euclid(12) as u32 + 1,
+        );
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+            ..self
+        }
+    }
+
+    // Abbreviated English month name
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+}
+
+// A date and time type the date sliders can show, implemented for chrono
+// DateTime<Tz> with the chrono feature and time OffsetDateTime with the time
+// feature. The slider track counts seconds since the Unix epoch.
+pub trait SliderDate: Clone + PartialEq + 'static {
+    // Time zone or offset dates are shown in
+    type Zone: Clone + PartialEq + 'static;
+    // Lengths of steps and selection spans
+    type Duration: Clone + PartialEq + 'static;
+    // Format of tooltips and pips, in the syntax of the backend
+    const DEFAULT_FORMAT: &'static str;
+
+    fn zone(&self) -> Self::Zone;
+    // Seconds since the Unix epoch
+    fn timestamp(&self) -> f64;
+    // The date 'timestamp' seconds after the Unix epoch, in 'zone'
+    fn from_timestamp(timestamp: f64, zone: &Self::Zone) -> Self;
+    // Local date and time fields in the zone of the date
+    fn local(&self) -> LocalDateTime;
+    // The earliest date with local time 'local' in 'zone', None for times
+    // skipped by a daylight saving transition
+    fn from_local(local: LocalDateTime, zone: &Self::Zone) -> Option<Self>;
+    fn now(zone: &Self::Zone) -> Self;
+    // Text of the date in 'format', invalid formats fall back to DEFAULT_FORMAT
+    fn format_with(&self, format: &str) -> String;
+    fn seconds(duration: &Self::Duration) -> f64;
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> SliderDate for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone + PartialEq + 'static,
+    Tz::Offset: std::fmt::Display,
+{
+    type Zone = Tz;
+    type Duration = chrono::Duration;
+    const DEFAULT_FORMAT: &'static str = "%Y-%m-%d %H:%M";
+
+    fn zone(&self) -> Tz {
+        self.timezone()
+    }
+
+    fn timestamp(&self) -> f64 {
+        self.timestamp() as f64
+    }
+
+    fn from_timestamp(timestamp: f64, zone: &Tz) -> Self {
+        let date = chrono::DateTime::from_timestamp(timestamp as i64, 0).unwrap();
+        date.with_timezone(zone)
+    }
+
+    fn local(&self) -> LocalDateTime {
+        use chrono::{Datelike, Timelike};
+        let naive = self.naive_local();
+        LocalDateTime::new(
+            naive.year(),
+            naive.month(),
+            naive.day(),
+            naive.hour(),
+            naive.minute(),
+            naive.second(),
+        )
+    }
+
+    fn from_local(local: LocalDateTime, zone: &Tz) -> Option<Self> {
+        let naive = chrono::NaiveDate::from_ymd_opt(local.year, local.month, local.day)?
+            .and_hms_opt(local.hour, local.minute, local.second)?;
+        zone.from_local_datetime(&naive).earliest()
+    }
+
+    fn now(zone: &Tz) -> Self {
+        chrono::Utc::now().with_timezone(zone)
+    }
+
+    fn format_with(&self, format: &str) -> String {
+        let items = chrono::format::StrftimeItems::new(format);
+        match items
+            .clone()
+            .any(|item| item == chrono::format::Item::Error)
+        {
+            true => self.format(Self::DEFAULT_FORMAT).to_string(),
+            false => self.format_with_items(items).to_string(),
+        }
+    }
+
+    fn seconds(duration: &chrono::Duration) -> f64 {
+        duration.num_seconds() as f64
+    }
+}
+
+#[cfg(feature = "time")]
+impl SliderDate for time::OffsetDateTime {
+    type Zone = time::UtcOffset;
+    type Duration = time::Duration;
+    const DEFAULT_FORMAT: &'static str = "[year]-[month]-[day] [hour]:[minute]";
+
+    fn zone(&self) -> time::UtcOffset {
+        self.offset()
+    }
+
+    fn timestamp(&self) -> f64 {
+        self.unix_timestamp() as f64
+    }
+
+    fn from_timestamp(timestamp: f64, zone: &time::UtcOffset) -> Self {
+        let date = time::OffsetDateTime::from_unix_timestamp(timestamp as i64).unwrap();
+        date.to_offset(*zone)
+    }
+
+    fn local(&self) -> LocalDateTime {
+        LocalDateTime::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+        )
+    }
+
+    fn from_local(local: LocalDateTime, zone: &time::UtcOffset) -> Option<Self> {
+        let month = time::Month::try_from(local.month as u8).ok()?;
+        let date = time::Date::from_calendar_date(local.year, month, local.day as u8).ok()?;
+        let time = time::Time::from_hms(local.hour as u8, local.minute as u8, local.second as u8);
+        Some(date.with_time(time.ok()?).assume_offset(*zone))
+    }
+
+    fn now(zone: &time::UtcOffset) -> Self {
+        time::OffsetDateTime::now_utc().to_offset(*zone)
+    }
+
+    fn format_with(&self, format: &str) -> String {
+        let render = |format: &str| {
+            let description = time::format_description::parse_borrowed::<2>(format).ok()?;
+            time::OffsetDateTime::format(*self, &description).ok()
+        };
+        render(format)
+            .or_else(|| render(Self::DEFAULT_FORMAT))
+            .unwrap_or_default()
+    }
+
+    fn seconds(duration: &time::Duration) -> f64 {
+        duration.whole_seconds() as f64
+    }
+}
@@ -0,0 +1,36 @@
+pub mod calendar;
+pub mod components;
+pub mod crossfilter;
+pub mod data;
+pub mod datetime;
+pub mod scale;
+pub mod value;
+
+pub use calendar::{CalendarUnit, ExcludedTime, RelativeRange};
+pub use components::allocationslider::{AllocationSlider, AllocationSliderProps};
+pub use components::categoryslider::{CategorySlider, CategorySliderProps};
+pub use components::crossfilter::{
+    CrossfilterProvider, CrossfilterProviderProps, CrossfilterSlider, CrossfilterSliderProps,
+    Crossfiltered,
+};
+pub use components::dateslider::{DatePips, DatePreset, DateSlider, DateSliderProps};
+pub use components::durationslider::{duration_range, DurationSlider, DurationSliderProps};
+pub use components::histogramslider::{HistogramSlider, HistogramSliderProps};
+pub use components::nouislider::{
+    Event as SliderEvent, FormattedValues, HandleAttributes, Pips, Range, Segment, Slider,
+    SliderProps,
+};
+pub use components::snapslider::{DataPoint, SnapSlider, SnapSliderProps};
+pub use components::timeofdayslider::{
+    format_time_of_day, time_of_day, TimeOfDaySlider, TimeOfDaySliderProps,
+};
+pub use components::typedslider::{TrackMapping, TypedEvent, TypedSlider, TypedSliderProps};
+pub use components::use_slider::{use_slider, SliderFn, UseSliderHandle};
+pub use crossfilter::{Crossfilter, RowKey};
+pub use data::{data_points, quantile, quantile_range, snap_range, Bins, Histogram, RangeIndex};
+pub use datetime::{LocalDateTime, SliderDate};
+pub use scale::{Linear, Log, Pow, Sqrt, ValueScale};
+pub use value::{humanize, SliderValue};
+
+#[cfg(feature = "derive")]
+pub use nouislider_yew_derive::SliderValue;
@@ -0,0 +1,89 @@
+use std::rc::Rc;
+use yew::Callback;
+
+use crate::components::typedslider::TrackMapping;
+
+// Maps values onto a linear slider track and back, for scales noUiSlider can
+// not express with its piecewise linear ranges
+pub trait ValueScale {
+    fn to_track(&self, value: f64) -> f64;
+    // Inverse of to_track
+    fn to_value(&self, track: f64) -> f64;
+}
+
+impl<S: ValueScale + 'static> From<S> for TrackMapping {
+    fn from(scale: S) -> Self {
+        let scale = Rc::new(scale);
+        let from = scale.clone();
+        Self {
+            to_track: Callback::from(move |value| scale.to_track(value)),
+            from_track: Callback::from(move |track| from.to_value(track)),
+        }
+    }
+}
+
+// Round away floating point noise from a round trip over the track, keeping 12 significant digits
+fn round(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let scale = 10f64.powi(12 - value.abs().log10().ceil() as i32);
+    (value * scale).round() / scale
+}
+
+// The track is the value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Linear;
+
+impl ValueScale for Linear {
+    fn to_track(&self, value: f64) -> f64 {
+        value
+    }
+
+    fn to_value(&self, track: f64) -> f64 {
+        track
+    }
+}
+
+// Equal track distances for equal ratios, such as 20 Hz to 20 kHz. Values must be positive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Log;
+
+impl ValueScale for Log {
+    fn to_track(&self, value: f64) -> f64 {
+        value.ln()
+    }
+
+    fn to_value(&self, track: f64) -> f64 {
+        round(track.exp())
+    }
+}
+
+// The track is the value raised to the exponent, keeping the sign of the value.
+// Exponents below 1 give more room to small values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pow(pub f64);
+
+impl ValueScale for Pow {
+    fn to_track(&self, value: f64) -> f64 {
+        value.signum() * value.abs().powf(self.0)
+    }
+
+    fn to_value(&self, track: f64) -> f64 {
+        round(track.signum() * track.abs().powf(1.0 / self.0))
+    }
+}
+
+// Pow(0.5)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sqrt;
+
+impl ValueScale for Sqrt {
+    fn to_track(&self, value: f64) -> f64 {
+        Pow(0.5).to_track(value)
+    }
+
+    fn to_value(&self, track: f64) -> f64 {
+        Pow(0.5).to_value(track)
+    }
+}
@@ -0,0 +1,268 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+
+// A value shown on a slider, noUiSlider itself only moves f64 handles
+pub trait SliderValue: Clone + PartialEq + 'static {
+    // Position of the value on the slider track
+    fn to_f64(&self) -> f64;
+    // Value at a position on the slider track
+    fn from_f64(value: f64) -> Self;
+    // Value at a position on the slider track, like 'like' in all but the
+    // position, such as the offset of a date. Defaults to from_f64.
+    fn from_f64_like(value: f64, _like: &Self) -> Self {
+        Self::from_f64(value)
+    }
+    // Text for tooltips and pips
+    fn format(&self) -> String;
+    // Inverse of format
+    fn parse(text: &str) -> Option<Self>;
+}
+
+impl SliderValue for f64 {
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M";
+#[cfg(feature = "chrono")]
+const DATE_OFFSET_FORMAT: &str = "%Y-%m-%d %H:%M %z";
+
+// Seconds since the Unix epoch
+#[cfg(feature = "chrono")]
+impl SliderValue for DateTime<Utc> {
+    fn to_f64(&self) -> f64 {
+        self.timestamp() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        DateTime::from_timestamp(value as i64, 0).unwrap()
+    }
+
+    fn format(&self) -> String {
+        self.format(DATE_FORMAT).to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(text.trim(), DATE_FORMAT)
+            .ok()
+            .map(|naive| naive.and_utc())
+    }
+}
+
+// Seconds since the Unix epoch, a TypedSlider reads values in the offset of its min
+#[cfg(feature = "chrono")]
+impl SliderValue for DateTime<FixedOffset> {
+    fn to_f64(&self) -> f64 {
+        self.timestamp() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        DateTime::<Utc>::from_f64(value).fixed_offset()
+    }
+
+    fn from_f64_like(value: f64, like: &Self) -> Self {
+        DateTime::<Utc>::from_f64(value).with_timezone(&like.timezone())
+    }
+
+    // Local time with its offset, such as 2024-03-31 02:30 +0200
+    fn format(&self) -> String {
+        self.format(DATE_OFFSET_FORMAT).to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        DateTime::parse_from_str(text.trim(), DATE_OFFSET_FORMAT).ok()
+    }
+}
+
+#[cfg(feature = "time")]
+const TIME_FORMAT: &[time::format_description::FormatItem] = time::macros::format_description!(
+    "[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory][offset_minute]"
+);
+
+// Seconds since the Unix epoch, a TypedSlider reads values in the offset of its min
+#[cfg(feature = "time")]
+impl SliderValue for time::OffsetDateTime {
+    fn to_f64(&self) -> f64 {
+        self.unix_timestamp() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        time::OffsetDateTime::from_unix_timestamp(value as i64).unwrap()
+    }
+
+    fn from_f64_like(value: f64, like: &Self) -> Self {
+        Self::from_f64(value).to_offset(like.offset())
+    }
+
+    fn format(&self) -> String {
+        time::OffsetDateTime::format(*self, TIME_FORMAT).unwrap()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        time::OffsetDateTime::parse(text.trim(), TIME_FORMAT).ok()
+    }
+}
+
+const DURATION_UNITS: [(&str, u64); 4] = [
+    ("day", 86400),
+    ("hour", 3600),
+    ("minute", 60),
+    ("second", 1),
+];
+
+// Whole seconds in the largest unit and the next smaller unit, such as "1 hour 30 minutes"
+pub fn humanize(seconds: f64) -> String {
+    let seconds = seconds.max(0.0).round() as u64;
+    let Some(i) = DURATION_UNITS.iter().position(|(_, unit)| seconds >= *unit) else {
+        return "0 seconds".to_string();
+    };
+    let parts = DURATION_UNITS[i..]
+        .iter()
+        .take(2)
+        .scan(seconds, |rest, (name, unit)| {
+            let count = *rest / unit;
+            *rest %= unit;
+            Some((count, name))
+        });
+    parts
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, name)| match count {
+            1 => format!("1 {name}"),
+            _ => format!("{count} {name}s"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Seconds of a humanized duration, inverse of humanize
+pub fn parse_humanized(text: &str) -> Option<f64> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() || !words.len().is_multiple_of(2) {
+        return None;
+    }
+    words.chunks(2).try_fold(0.0, |seconds, pair| {
+        let count: f64 = pair[0].parse().ok()?;
+        let name = pair[1].trim_end_matches('s');
+        let (_, unit) = DURATION_UNITS.iter().find(|(unit, _)| *unit == name)?;
+        Some(seconds + count * *unit as f64)
+    })
+}
+
+// Seconds, formatted humanized
+impl SliderValue for std::time::Duration {
+    fn to_f64(&self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        std::time::Duration::from_secs_f64(value.max(0.0))
+    }
+
+    fn format(&self) -> String {
+        humanize(self.as_secs_f64())
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        parse_humanized(text).map(Self::from_f64)
+    }
+}
+
+// Seconds, formatted humanized
+#[cfg(feature = "chrono")]
+impl SliderValue for chrono::Duration {
+    fn to_f64(&self) -> f64 {
+        self.num_seconds() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        chrono::Duration::seconds(value as i64)
+    }
+
+    fn format(&self) -> String {
+        humanize(self.num_seconds() as f64)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        parse_humanized(text).map(Self::from_f64)
+    }
+}
+
+// Seconds, formatted humanized
+#[cfg(feature = "time")]
+impl SliderValue for time::Duration {
+    fn to_f64(&self) -> f64 {
+        self.as_seconds_f64()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        time::Duration::seconds_f64(value)
+    }
+
+    fn format(&self) -> String {
+        humanize(self.as_seconds_f64())
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        parse_humanized(text).map(Self::from_f64)
+    }
+}
+
+// Seconds since midnight, the end of the day wraps around to midnight
+#[cfg(feature = "chrono")]
+impl SliderValue for chrono::NaiveTime {
+    fn to_f64(&self) -> f64 {
+        use chrono::Timelike;
+        self.num_seconds_from_midnight() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        let seconds = (value as i64).rem_euclid(86400) as u32;
+        chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0).unwrap()
+    }
+
+    fn format(&self) -> String {
+        self.format("%H:%M").to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        chrono::NaiveTime::parse_from_str(text.trim(), "%H:%M").ok()
+    }
+}
+
+// Seconds since midnight, the end of the day wraps around to midnight
+#[cfg(feature = "time")]
+impl SliderValue for time::Time {
+    fn to_f64(&self) -> f64 {
+        let (hour, minute, second) = self.as_hms();
+        (hour as u32 * 3600 + minute as u32 * 60 + second as u32) as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        let seconds = (value as i64).rem_euclid(86400) as u32;
+        let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        time::Time::from_hms(hour as u8, minute as u8, second as u8).unwrap()
+    }
+
+    fn format(&self) -> String {
+        format!("{:02}:{:02}", self.hour(), self.minute())
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (hour, minute) = text.trim().split_once(':')?;
+        time::Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+    }
+}
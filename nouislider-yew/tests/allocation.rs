@@ -0,0 +1,32 @@
+use nouislider_yew::components::allocationslider::constrain;
+
+fn constrained(mut handles: Vec<f64>, minimums: &[f64]) -> Vec<f64> {
+    constrain(&mut handles, minimums);
+    handles
+}
+
+#[test]
+fn handles_stop_at_minimums() {
+    let minimums = [10.0, 20.0, 30.0];
+    assert_eq!(constrained(vec![40.0, 60.0], &minimums), vec![40.0, 60.0]);
+    assert_eq!(constrained(vec![5.0, 60.0], &minimums), vec![10.0, 60.0]);
+    assert_eq!(constrained(vec![40.0, 50.0], &minimums), vec![40.0, 60.0]);
+    assert_eq!(constrained(vec![40.0, 90.0], &minimums), vec![40.0, 70.0]);
+    // The first handle keeps room for the middle segment before the last
+    assert_eq!(constrained(vec![65.0, 70.0], &minimums), vec![50.0, 70.0]);
+}
+
+#[test]
+fn missing_minimums_are_zero() {
+    assert_eq!(constrained(vec![0.0, 100.0], &[]), vec![0.0, 100.0]);
+    assert_eq!(constrained(vec![0.0, 100.0], &[5.0]), vec![5.0, 100.0]);
+    assert_eq!(constrained(vec![], &[50.0]), Vec::<f64>::new());
+}
+
+#[test]
+fn minimums_over_100_are_scaled_down() {
+    // 100, 100 and 200 fit as 25, 25 and 50
+    let minimums = [100.0, 100.0, 200.0];
+    assert_eq!(constrained(vec![10.0, 90.0], &minimums), vec![25.0, 50.0]);
+    assert_eq!(constrained(vec![60.0, 70.0], &minimums), vec![25.0, 50.0]);
+}
@@ -0,0 +1,154 @@
+use nouislider_yew::calendar::tick_label;
+use nouislider_yew::{CalendarUnit, LocalDateTime};
+
+#[test]
+fn local_date_time_fields() {
+    let local = LocalDateTime::new(2024, 3, 13, 10, 30, 15);
+    assert_eq!(LocalDateTime::from_seconds(local.seconds()), local);
+    assert_eq!(local.weekday(), 2);
+    assert_eq!(local.ordinal0(), 31 + 29 + 12);
+    assert_eq!(local.month_name(), "Mar");
+    assert_eq!(LocalDateTime::date(1970, 1, 1).seconds(), 0);
+    // Before the epoch
+    let local = LocalDateTime::new(1969, 12, 31, 23, 0, 0);
+    assert_eq!(local.seconds(), -3600);
+    assert_eq!(LocalDateTime::from_seconds(-3600), local);
+    assert_eq!(local.add_days(1), LocalDateTime::new(1970, 1, 1, 23, 0, 0));
+}
+
+#[test]
+fn tick_labels_show_the_larger_unit() {
+    let label = |y, m, d, h, unit| tick_label(LocalDateTime::new(y, m, d, h, 0, 0), unit);
+    assert_eq!(label(2024, 3, 13, 10, CalendarUnit::Hour), "10:00");
+    assert_eq!(label(2024, 3, 13, 0, CalendarUnit::Hour), "Mar 13");
+    assert_eq!(label(2024, 3, 13, 0, CalendarUnit::Week), "Mar 13");
+    assert_eq!(label(2024, 3, 1, 0, CalendarUnit::Month), "Mar");
+    assert_eq!(label(2024, 1, 1, 0, CalendarUnit::Day), "2024");
+    assert_eq!(label(2024, 1, 1, 0, CalendarUnit::Quarter), "2024");
+    assert_eq!(label(2025, 1, 1, 0, CalendarUnit::Year), "2025");
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_backend {
+    use chrono::{DateTime, TimeZone, Utc};
+    use chrono_tz::Europe::Stockholm;
+    use chrono_tz::Tz;
+    use nouislider_yew::calendar::{calendar_range, from_local, ticks};
+    use nouislider_yew::{CalendarUnit, ExcludedTime, LocalDateTime};
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> f64 {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0)
+            .unwrap()
+            .timestamp() as f64
+    }
+
+    fn stockholm(local: LocalDateTime) -> f64 {
+        from_local::<DateTime<Tz>>(local, &Stockholm)
+    }
+
+    #[test]
+    fn local_times_around_dst() {
+        // Summer time starts 2024-03-31 02:00, skipped times move to 03:00
+        assert_eq!(
+            stockholm(LocalDateTime::new(2024, 3, 31, 2, 30, 0)),
+            utc(2024, 3, 31, 1, 0)
+        );
+        assert_eq!(
+            stockholm(LocalDateTime::new(2024, 3, 31, 3, 0, 0)),
+            utc(2024, 3, 31, 1, 0)
+        );
+        // Summer time ends 2024-10-27 03:00, repeated times are the earliest
+        assert_eq!(
+            stockholm(LocalDateTime::new(2024, 10, 27, 2, 30, 0)),
+            utc(2024, 10, 27, 0, 30)
+        );
+    }
+
+    #[test]
+    fn day_steps_across_dst() {
+        let (min, max) = (utc(2024, 3, 29, 23, 0), utc(2024, 4, 1, 22, 0));
+        let range = calendar_range::<DateTime<Tz>>(
+            min,
+            max,
+            &Stockholm,
+            CalendarUnit::Day,
+            &ExcludedTime::default(),
+        );
+        let mut steps: Vec<(f64, f64)> = (range.0.values())
+            .filter(|value| value.len() == 2)
+            .map(|value| (value[0], value[1]))
+            .collect();
+        steps.sort_by(|a, b| a.0.total_cmp(&b.0));
+        // Midnight of March 31st is still winter time, the day is 23 hours long
+        assert_eq!(
+            steps,
+            [
+                (min, 86400.0),
+                (utc(2024, 3, 30, 23, 0), 82800.0),
+                (utc(2024, 3, 31, 22, 0), 86400.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn units_truncate_and_add() {
+        let local = LocalDateTime::new(2024, 1, 31, 10, 45, 30);
+        assert_eq!(
+            CalendarUnit::Week.truncate(local),
+            LocalDateTime::date(2024, 1, 29)
+        );
+        assert_eq!(
+            CalendarUnit::Quarter.truncate(local),
+            LocalDateTime::date(2024, 1, 1)
+        );
+        // Days past the end of the month are clamped
+        assert_eq!(
+            CalendarUnit::Month.add(local, 1),
+            LocalDateTime::new(2024, 2, 29, 10, 45, 30)
+        );
+        assert_eq!(
+            CalendarUnit::Year.add(LocalDateTime::date(2024, 2, 29), 1),
+            LocalDateTime::date(2025, 2, 28)
+        );
+    }
+
+    #[test]
+    fn unrepresentable_local_time_reads_as_utc() {
+        // Past the last year chrono can represent
+        let local = LocalDateTime::date(300_000, 1, 1);
+        assert_eq!(
+            from_local::<DateTime<Utc>>(local, &Utc),
+            local.seconds() as f64
+        );
+    }
+
+    #[test]
+    fn month_and_year_ticks() {
+        let (unit, dates) =
+            ticks::<DateTime<Utc>>(utc(2024, 1, 15, 0, 0), utc(2024, 12, 15, 0, 0), &Utc, 12);
+        assert_eq!(unit, CalendarUnit::Month);
+        let months: Vec<f64> = (2..=12).map(|month| utc(2024, month, 1, 0, 0)).collect();
+        assert_eq!(dates, months);
+
+        // Every fifth year fits in 8 ticks
+        let (unit, dates) =
+            ticks::<DateTime<Utc>>(utc(2000, 1, 1, 0, 0), utc(2030, 1, 1, 0, 0), &Utc, 8);
+        assert_eq!(unit, CalendarUnit::Year);
+        let years: Vec<f64> = (1..=5).map(|i| utc(2000 + i * 5, 1, 1, 0, 0)).collect();
+        assert_eq!(dates, years);
+    }
+
+    #[test]
+    fn hour_ticks_skip_the_dst_gap() {
+        let (unit, dates) = ticks::<DateTime<Tz>>(
+            utc(2024, 3, 30, 23, 0),
+            utc(2024, 3, 31, 4, 0),
+            &Stockholm,
+            8,
+        );
+        assert_eq!(unit, CalendarUnit::Hour);
+        // 01:00, then 03:00 as 02:00 does not exist, 04:00 and 05:00
+        let hours: Vec<f64> = (0..4).map(|hour| utc(2024, 3, 31, hour, 0)).collect();
+        assert_eq!(dates, hours);
+    }
+}
@@ -0,0 +1,341 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveTime, TimeZone, Utc};
+use gloo_timers::future::sleep;
+use gloo_utils::document;
+use nouislider_yew::{
+    AllocationSlider, DatePips, DatePreset, DateSlider, Range, RelativeRange, Slider, SliderEvent,
+    TimeOfDaySlider,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::AppHandle;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Stand-in for noUiSlider which keeps the handle values, snaps them to the step
+// and counts the calls to set
+#[wasm_bindgen(inline_js = r#"
+const sliders = [];
+let setCalls = 0;
+
+function live() {
+    return sliders.filter((slider) => !slider.destroyed).pop();
+}
+
+export function install() {
+    globalThis.noUiSlider = {
+        create: class {
+            constructor(target, options) {
+                this.events = {};
+                this.step = options.step;
+                this.values = [].concat(options.start).map((value) => this.snap(value));
+                this.destroyed = false;
+                sliders.push(this);
+            }
+            snap(value) {
+                return this.step ? Math.round(value / this.step) * this.step : value;
+            }
+            on(name, callback) {
+                this.events[name] = callback;
+                if (name === "update") {
+                    this.fire(name);
+                }
+            }
+            off(name) {
+                delete this.events[name];
+            }
+            destroy() {
+                this.destroyed = true;
+            }
+            get() {
+                return this.values.length === 1 ? this.values[0] : this.values.slice();
+            }
+            set(values) {
+                setCalls += 1;
+                this.values = [].concat(values).map((value) => this.snap(value));
+                this.fire("update");
+                this.fire("set");
+            }
+            updateOptions(options) {
+                if ("step" in options) {
+                    this.step = options.step;
+                }
+            }
+            getPositions() {
+                return this.values.map(() => 0);
+            }
+            getTooltips() {
+                return null;
+            }
+            fire(name) {
+                const callback = this.events[name];
+                if (callback) {
+                    const positions = this.values.map(() => 0);
+                    callback(this.values.map(String), 0, this.values, false, positions, this);
+                }
+            }
+        },
+    };
+}
+
+// Move the handles of the newest live slider like a user would
+export function drag(values) {
+    const slider = live();
+    slider.values = Array.from(values);
+    slider.fire("slide");
+    slider.fire("update");
+}
+
+export function handles() {
+    return Float64Array.from(live().values);
+}
+
+export function live_sliders() {
+    return sliders.filter((slider) => !slider.destroyed).length;
+}
+
+export function sets() {
+    return setCalls;
+}
+"#)]
+extern "C" {
+    fn install();
+    fn drag(values: Vec<f64>);
+    fn handles() -> Vec<f64>;
+    fn live_sliders() -> usize;
+    fn sets() -> usize;
+}
+
+async fn mount<C: BaseComponent>(props: C::Properties) -> (AppHandle<C>, Element) {
+    install();
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let app = yew::Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    sleep(Duration::ZERO).await;
+    (app, root)
+}
+
+fn range() -> Range {
+    Range(HashMap::from([
+        ("min".to_string(), vec![0.0]),
+        ("max".to_string(), vec![100.0]),
+    ]))
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    #[prop_or_default]
+    start: Vec<f64>,
+    value: Option<Vec<f64>>,
+    connect: Option<Vec<bool>>,
+    // Changed to render the app again with the same slider options
+    #[prop_or_default]
+    render: usize,
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    // A new callback on every render, like parents usually pass
+    let onupdate = Callback::from(|_: SliderEvent| ());
+    html! {
+        <Slider
+            start={props.start.clone()}
+            value={props.value.clone()}
+            range={range()}
+            connect={props.connect.clone()}
+            {onupdate}
+        />
+    }
+}
+
+// Renders on every update with a value off the step
+#[function_component]
+fn Snapping() -> Html {
+    let updates = use_state(|| 0);
+    let onupdate = Callback::from(move |_: SliderEvent| updates.set(*updates + 1));
+    html! {
+        <Slider value={vec![33.3]} range={range()} step={1.0} {onupdate} />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn controlled_value_is_applied_again() {
+    let props = |render| AppProps {
+        start: vec![],
+        value: Some(vec![20.0, 80.0]),
+        connect: None,
+        render,
+    };
+    let (mut app, root) = mount::<App>(props(0)).await;
+    drag(vec![30.0, 80.0]);
+    sleep(Duration::ZERO).await;
+    // The same value again puts the handles back
+    app.update(props(1));
+    sleep(Duration::ZERO).await;
+    assert_eq!(handles(), [20.0, 80.0]);
+
+    app.destroy();
+    root.remove();
+}
+
+#[wasm_bindgen_test]
+async fn rebuild_keeps_uncontrolled_handles() {
+    let props = |connect| AppProps {
+        start: vec![20.0, 80.0],
+        value: None,
+        connect,
+        render: 0,
+    };
+    let (mut app, root) = mount::<App>(props(None)).await;
+    let live = live_sliders();
+    drag(vec![30.0, 70.0]);
+    sleep(Duration::ZERO).await;
+    // Connect can not be updated, the slider is rebuilt
+    app.update(props(Some(vec![false, true, false])));
+    sleep(Duration::ZERO).await;
+    assert_eq!(live_sliders(), live);
+    assert_eq!(handles(), [30.0, 70.0]);
+
+    app.destroy();
+    root.remove();
+}
+
+#[wasm_bindgen_test]
+async fn snapped_value_is_set_once() {
+    let (app, root) = mount::<Snapping>(()).await;
+    let before = sets();
+    for _ in 0..5 {
+        sleep(Duration::ZERO).await;
+    }
+    assert_eq!(handles(), [33.0]);
+    // Setting 33.3 again would snap to 33 and render again, without end
+    assert!(sets() - before <= 1);
+
+    app.destroy();
+    root.remove();
+}
+
+fn march(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, day, 0, 0, 0).unwrap()
+}
+
+#[function_component]
+fn Dates() -> Html {
+    let presets = vec![DatePreset::new(
+        "Last day",
+        RelativeRange::Last(Duration::from_secs(86400)),
+    )];
+    html! {
+        <DateSlider<DateTime<Utc>>
+            min={march(1)}
+            max={march(31)}
+            now={march(15)}
+            {presets}
+            pips={DatePips::None}
+        />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn same_preset_twice() {
+    let (app, root) = mount::<Dates>(()).await;
+    let preset = vec![march(14).timestamp() as f64, march(15).timestamp() as f64];
+    let button: HtmlElement = (root.query_selector("button").unwrap().unwrap())
+        .dyn_into()
+        .unwrap();
+
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(handles(), preset);
+
+    drag(vec![
+        march(5).timestamp() as f64,
+        march(25).timestamp() as f64,
+    ]);
+    sleep(Duration::ZERO).await;
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(handles(), preset);
+
+    app.destroy();
+    root.remove();
+}
+
+thread_local! {
+    static SHARES: RefCell<Vec<f64>> = const { RefCell::new(Vec::new()) };
+}
+
+// The second segment needs 20%, more than the margin of 10% from the third
+#[function_component]
+fn Budget() -> Html {
+    let onupdate = Callback::from(|shares| SHARES.with(|last| *last.borrow_mut() = shares));
+    html! {
+        <AllocationSlider
+            shares={vec![25.0, 25.0, 25.0, 25.0]}
+            minimums={vec![0.0, 20.0, 10.0, 0.0]}
+            {onupdate}
+        />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn handles_stop_at_minimums_on_every_event() {
+    let (app, root) = mount::<Budget>(()).await;
+    for handle in [40.0, 42.0] {
+        drag(vec![25.0, handle, 75.0]);
+        sleep(Duration::ZERO).await;
+        assert_eq!(handles(), [25.0, 45.0, 75.0]);
+        SHARES.with(|shares| assert_eq!(*shares.borrow(), [25.0, 20.0, 30.0, 25.0]));
+    }
+
+    app.destroy();
+    root.remove();
+}
+
+thread_local! {
+    static TIMES: RefCell<Vec<NaiveTime>> = const { RefCell::new(Vec::new()) };
+}
+
+fn hour(hour: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+}
+
+#[function_component]
+fn QuietHours() -> Html {
+    let onchange = Callback::from(|times| TIMES.with(|last| *last.borrow_mut() = times));
+    html! {
+        <TimeOfDaySlider<NaiveTime> wrap=true {onchange} />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn flip_selection_across_midnight() {
+    let (app, root) = mount::<QuietHours>(()).await;
+    let button: HtmlElement = (root.query_selector("button").unwrap().unwrap())
+        .dyn_into()
+        .unwrap();
+    drag(vec![6.0 * 3600.0, 22.0 * 3600.0]);
+    sleep(Duration::ZERO).await;
+
+    // The handles can not pass each other, 22:00 to 06:00 is a flip away
+    button.click();
+    sleep(Duration::ZERO).await;
+    TIMES.with(|times| assert_eq!(*times.borrow(), [hour(22), hour(6)]));
+    assert_eq!(handles(), [6.0 * 3600.0, 22.0 * 3600.0]);
+    assert_eq!(button.get_attribute("aria-pressed").unwrap(), "true");
+
+    button.click();
+    sleep(Duration::ZERO).await;
+    TIMES.with(|times| assert_eq!(*times.borrow(), [hour(6), hour(22)]));
+
+    app.destroy();
+    root.remove();
+}
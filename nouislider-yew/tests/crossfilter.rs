@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use nouislider_yew::{Bins, Crossfilter, RowKey};
+
+struct Sale {
+    price: f64,
+    rating: f64,
+}
+
+fn crossfilter() -> Crossfilter<Sale> {
+    let sales = [
+        (10.0, 1.0),
+        (20.0, 5.0),
+        (30.0, 4.0),
+        (40.0, 2.0),
+        (50.0, 5.0),
+    ];
+    let rows = (sales.iter())
+        .map(|(price, rating)| Sale {
+            price: *price,
+            rating: *rating,
+        })
+        .collect();
+    Crossfilter::new(Rc::new(rows))
+}
+
+#[test]
+fn filters_combine() {
+    let mut crossfilter = crossfilter();
+    let price = crossfilter.dimension("price", RowKey::from(|sale: &Sale| sale.price));
+    let rating = crossfilter.dimension("rating", RowKey::from(|sale: &Sale| sale.rating));
+    assert_eq!(crossfilter.selected().count(), 5);
+
+    crossfilter.filter(price, Some((15.0, 45.0)));
+    assert_eq!(crossfilter.selected().collect::<Vec<_>>(), [1, 2, 3]);
+    crossfilter.filter(rating, Some((4.0, 5.0)));
+    assert_eq!(crossfilter.selected().collect::<Vec<_>>(), [1, 2]);
+    // Moving a filter past the other end
+    crossfilter.filter(price, Some((45.0, 60.0)));
+    assert_eq!(crossfilter.selected().collect::<Vec<_>>(), [4]);
+    crossfilter.filter(price, None);
+    assert_eq!(crossfilter.selected().collect::<Vec<_>>(), [1, 2, 4]);
+}
+
+#[test]
+fn histograms_ignore_own_filter() {
+    let mut crossfilter = crossfilter();
+    let price = crossfilter.dimension("price", RowKey::from(|sale: &Sale| sale.price));
+    let rating = crossfilter.dimension("rating", RowKey::from(|sale: &Sale| sale.rating));
+    crossfilter.filter(price, Some((10.0, 20.0)));
+    crossfilter.filter(rating, Some((5.0, 5.0)));
+
+    let prices = crossfilter.histogram(price, Bins::Count(4), 10.0, 50.0);
+    assert_eq!(prices.counts, [0, 1, 0, 1]);
+    let ratings = crossfilter.histogram(rating, Bins::Width(1.0), 1.0, 5.0);
+    assert_eq!(ratings.counts, [1, 0, 0, 1]);
+}
+
+#[test]
+fn dimensions_are_shared_by_name() {
+    let mut crossfilter = crossfilter();
+    let price = crossfilter.dimension("price", RowKey::from(|sale: &Sale| sale.price));
+    let again = crossfilter.dimension("price", RowKey::from(|sale: &Sale| -sale.price));
+    assert_eq!(price, again);
+    assert_eq!(crossfilter.extent(price), Some((10.0, 50.0)));
+}
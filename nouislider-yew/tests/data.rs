@@ -0,0 +1,113 @@
+use nouislider_yew::{data_points, quantile, quantile_range, snap_range, Bins, Histogram, Range};
+use std::collections::HashMap;
+
+fn range(entries: &[(&str, f64)]) -> Range {
+    Range(HashMap::from_iter(
+        (entries.iter()).map(|(key, value)| (key.to_string(), vec![*value])),
+    ))
+}
+
+#[test]
+fn snap_range_of_values() {
+    assert_eq!(
+        snap_range(&[0.0, 1.0, 4.0]),
+        range(&[("min", 0.0), ("25%", 1.0), ("max", 4.0)])
+    );
+    assert_eq!(snap_range(&[]), Range::default());
+}
+
+#[test]
+fn snap_range_of_a_single_value() {
+    assert_eq!(snap_range(&[3.0]), range(&[("min", 3.0), ("max", 3.0)]));
+    assert_eq!(
+        snap_range(&[3.0, 3.0, 3.0]),
+        range(&[("min", 3.0), ("max", 3.0)])
+    );
+}
+
+#[test]
+fn quantiles_interpolate() {
+    let sorted = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert_eq!(quantile(&sorted, 0.0), 1.0);
+    assert_eq!(quantile(&sorted, 0.5), 4.0);
+    assert_eq!(quantile(&sorted, 0.875), 12.0);
+    assert_eq!(quantile(&sorted, 1.5), 16.0);
+    assert_eq!(quantile(&sorted, -1.0), 1.0);
+}
+
+#[test]
+fn quantiles_of_few_values() {
+    assert!(quantile(&[], 0.5).is_nan());
+    assert_eq!(quantile(&[3.0], 0.0), 3.0);
+    assert_eq!(quantile(&[3.0], 0.7), 3.0);
+    assert_eq!(quantile_range(&[], 4), Range::default());
+    assert_eq!(
+        quantile_range(&[3.0], 4),
+        range(&[("min", 3.0), ("max", 3.0)])
+    );
+}
+
+#[test]
+fn quantile_range_skips_repeated_breakpoints() {
+    let sorted = [0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 100.0];
+    assert_eq!(
+        quantile_range(&sorted, 4),
+        range(&[("min", 0.0), ("50%", 1.0), ("75%", 3.0), ("max", 100.0)])
+    );
+}
+
+#[test]
+fn data_points_are_distinct_and_sorted() {
+    let data = [3.0, 1.0, 3.0, 2.0, 1.0];
+    // The first occurrence of each value is kept
+    assert_eq!(data_points(&data, 10), [(1, 1.0), (3, 2.0), (0, 3.0)]);
+    assert_eq!(data_points(&[], 10), []);
+}
+
+#[test]
+fn data_points_thin_out_evenly() {
+    let data: Vec<f64> = (0..101).map(|i| i as f64).collect();
+    let points = data_points(&data, 5);
+    assert_eq!(
+        points,
+        [(0, 0.0), (25, 25.0), (50, 50.0), (75, 75.0), (100, 100.0)]
+    );
+}
+
+#[test]
+fn data_points_keep_first_and_last() {
+    let data: Vec<f64> = (0..101).map(|i| i as f64).collect();
+    for max_points in [0, 1, 2] {
+        assert_eq!(data_points(&data, max_points), [(0, 0.0), (100, 100.0)]);
+    }
+    assert_eq!(data_points(&[5.0, 5.0], 0), [(0, 5.0)]);
+}
+
+#[test]
+fn bin_edges() {
+    assert_eq!(Bins::Count(4).edges(0.0, 8.0), [0.0, 2.0, 4.0, 6.0, 8.0]);
+    assert_eq!(Bins::Width(3.0).edges(0.0, 9.0), [0.0, 3.0, 6.0, 9.0]);
+    // The last bin is narrower when the width does not divide the range
+    assert_eq!(
+        Bins::Width(3.0).edges(0.0, 10.0),
+        [0.0, 3.0, 6.0, 9.0, 10.0]
+    );
+    assert_eq!(Bins::Width(0.1).edges(0.0, 0.3).len(), 4);
+    assert_eq!(Bins::Count(0).edges(0.0, 8.0), [0.0, 8.0]);
+    assert_eq!(Bins::Width(0.0).edges(0.0, 8.0), [0.0, 8.0]);
+}
+
+#[test]
+fn histogram_of_uneven_bins() {
+    let values = [-1.0, 0.0, 2.9, 3.0, 9.0, 9.5, 10.0, 11.0];
+    let histogram = Histogram::from_values(Bins::Width(3.0), 0.0, 10.0, &values);
+    // The last bin holds its upper edge, values out of range are left out
+    assert_eq!(histogram.counts, [2, 1, 0, 3]);
+    assert_eq!(histogram.max_count(), 3);
+    assert_eq!(histogram.bin(10.0), Some(3));
+    assert_eq!(histogram.bin(-0.1), None);
+
+    let histogram = Histogram::from_values(Bins::Count(3), 0.0, 10.0, &values);
+    assert_eq!(histogram.counts, [3, 0, 3]);
+    assert_eq!(Histogram::default().bin(1.0), None);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "derive")]
+
+use nouislider_yew::SliderValue;
+
+#[derive(Clone, Debug, PartialEq, SliderValue)]
+#[slider(display)]
+enum Channel {
+    Stable,
+    Beta,
+    #[slider(label = "Nightly build")]
+    Nightly,
+}
+
+#[test]
+fn variants_map_to_indices() {
+    assert_eq!(Channel::Beta.to_f64(), 1.0);
+    assert_eq!(Channel::from_f64(2.0), Channel::Nightly);
+    assert_eq!(Channel::from_f64(0.4), Channel::Stable);
+    assert_eq!(Channel::from_f64(-1.0), Channel::Stable);
+    assert_eq!(Channel::from_f64(7.0), Channel::Nightly);
+}
+
+#[test]
+fn labels_format_and_parse() {
+    assert_eq!(Channel::Stable.format(), "Stable");
+    assert_eq!(Channel::Nightly.to_string(), "Nightly build");
+    assert_eq!(Channel::parse(" Nightly build "), Some(Channel::Nightly));
+    assert_eq!(Channel::parse("Nightly"), None);
+}
+
+#[derive(Clone, Debug, PartialEq, SliderValue)]
+enum Level {
+    Low,
+    High,
+}
+
+// Display of its own, the derive leaves it alone without #[slider(display)]
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "level {}", self.to_f64())
+    }
+}
+
+#[test]
+fn display_is_opt_in() {
+    assert_eq!(Level::High.format(), "High");
+    assert_eq!(Level::High.to_string(), "level 1");
+}
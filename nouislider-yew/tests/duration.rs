@@ -0,0 +1,36 @@
+use nouislider_yew::value::parse_humanized;
+use nouislider_yew::{humanize, SliderValue};
+use std::time::Duration;
+
+#[test]
+fn humanize_shows_two_units() {
+    assert_eq!(humanize(0.0), "0 seconds");
+    assert_eq!(humanize(-5.0), "0 seconds");
+    assert_eq!(humanize(1.0), "1 second");
+    assert_eq!(humanize(90.0), "1 minute 30 seconds");
+    assert_eq!(humanize(3600.0), "1 hour");
+    // Seconds below the two largest units are left out
+    assert_eq!(humanize(5400.0 + 59.0), "1 hour 30 minutes");
+    assert_eq!(humanize(90.0 * 86400.0 + 7200.0), "90 days 2 hours");
+}
+
+#[test]
+fn humanized_round_trip() {
+    for seconds in [1.0, 59.0, 90.0, 3600.0, 5400.0, 86400.0, 93600.0] {
+        assert_eq!(parse_humanized(&humanize(seconds)), Some(seconds));
+    }
+    let duration = Duration::from_secs(2 * 86400 + 3600);
+    assert_eq!(Duration::parse(&duration.format()), Some(duration));
+}
+
+#[test]
+fn parse_humanized_rejects_other_text() {
+    assert_eq!(
+        parse_humanized(" 2 days  1 hour "),
+        Some(2.0 * 86400.0 + 3600.0)
+    );
+    assert_eq!(parse_humanized(""), None);
+    assert_eq!(parse_humanized("2 days 1"), None);
+    assert_eq!(parse_humanized("2 weeks"), None);
+    assert_eq!(parse_humanized("two days"), None);
+}
@@ -0,0 +1,60 @@
+use nouislider_yew::ExcludedTime;
+
+#[test]
+fn nothing_excluded_by_default() {
+    let excluded = ExcludedTime::default();
+    assert!(excluded.is_empty());
+    assert!(!excluded.contains(100.0));
+    assert_eq!(excluded.to_track(100.0), 100.0);
+    assert_eq!(excluded.from_track(100.0), 100.0);
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_backend {
+    use chrono::{DateTime, TimeZone, Utc};
+    use nouislider_yew::ExcludedTime;
+
+    fn utc(d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, d, h, 0, 0).unwrap()
+    }
+
+    fn seconds(d: u32, h: u32) -> f64 {
+        utc(d, h).timestamp() as f64
+    }
+
+    #[test]
+    fn intervals_collapse_on_the_track() {
+        // Overlapping intervals merge into 10:00 to 14:00
+        let excluded = ExcludedTime::new([(utc(4, 10), utc(4, 12)), (utc(4, 11), utc(4, 14))]);
+        assert!(excluded.contains(seconds(4, 10)));
+        assert!(excluded.contains(seconds(4, 13)));
+        assert!(!excluded.contains(seconds(4, 14)));
+        assert!(!excluded.contains(seconds(4, 9)));
+
+        let hours = 4.0 * 3600.0;
+        assert_eq!(excluded.to_track(seconds(4, 9)), seconds(4, 9));
+        assert_eq!(excluded.to_track(seconds(4, 12)), seconds(4, 10));
+        assert_eq!(excluded.to_track(seconds(4, 15)), seconds(4, 15) - hours);
+        assert_eq!(excluded.from_track(seconds(4, 10)), seconds(4, 10));
+        assert_eq!(excluded.from_track(seconds(4, 15) - hours), seconds(4, 15));
+    }
+
+    #[test]
+    fn weekends() {
+        // Monday 4 March to Wednesday 13 March 2024
+        let excluded = ExcludedTime::weekends(&utc(4, 0), &utc(13, 0), &Utc);
+        assert!(!excluded.contains(seconds(8, 23)));
+        assert!(excluded.contains(seconds(9, 0)));
+        assert!(excluded.contains(seconds(10, 23)));
+        assert!(!excluded.contains(seconds(11, 0)));
+        // Friday end and Monday start meet on the track
+        assert_eq!(
+            excluded.to_track(seconds(9, 0)),
+            excluded.to_track(seconds(11, 0))
+        );
+        assert_eq!(
+            excluded.from_track(excluded.to_track(seconds(12, 8))),
+            seconds(12, 8)
+        );
+    }
+}
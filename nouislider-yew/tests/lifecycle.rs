@@ -0,0 +1,110 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use gloo_timers::future::sleep;
+use gloo_utils::document;
+use nouislider_yew::{Range, Slider, SliderEvent};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Stand-in for noUiSlider which remembers every slider it created, so events
+// can still be fired at sliders after they have been destroyed
+#[wasm_bindgen(inline_js = r#"
+const sliders = [];
+
+export function install() {
+    globalThis.noUiSlider = {
+        create: class {
+            constructor(target, options) {
+                this.events = {};
+                this.values = options.start;
+                this.destroyed = false;
+                sliders.push(this);
+            }
+            on(name, callback) {
+                this.events[name] = callback;
+                if (name === "update") {
+                    this.fire(name);
+                }
+            }
+            off(name) {
+                delete this.events[name];
+            }
+            destroy() {
+                this.destroyed = true;
+            }
+            fire(name) {
+                const callback = this.events[name];
+                if (!callback) {
+                    return;
+                }
+                if (name === "hover") {
+                    callback(this.values[0]);
+                } else {
+                    const positions = this.values.map(() => 0);
+                    callback(this.values.map(String), 0, this.values, false, positions, this);
+                }
+            }
+            getTooltips() {
+                return null;
+            }
+        },
+    };
+}
+
+export function fire_all() {
+    sliders.forEach((slider) => Object.keys(slider.events).forEach((name) => slider.fire(name)));
+}
+
+export function live_sliders() {
+    return sliders.filter((slider) => !slider.destroyed).length;
+}
+
+export function bound_events() {
+    return sliders.reduce((count, slider) => count + Object.keys(slider.events).length, 0);
+}
+"#)]
+extern "C" {
+    fn install();
+    fn fire_all();
+    fn live_sliders() -> usize;
+    fn bound_events() -> usize;
+}
+
+#[function_component]
+fn App() -> Html {
+    let range = Range(HashMap::from([
+        ("min".to_string(), vec![0.0]),
+        ("max".to_string(), vec![100.0]),
+    ]));
+    let onupdate = Callback::from(|_: SliderEvent| ());
+    let onhover = Callback::from(|_: SliderEvent| ());
+    html! {
+        <Slider start={vec![20.0, 80.0]} {range} {onupdate} {onhover} />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn mount_and_unmount() {
+    install();
+    for _ in 0..20 {
+        let root = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&root).unwrap();
+        let app = yew::Renderer::<App>::with_root(root.clone()).render();
+        sleep(Duration::ZERO).await;
+        assert_eq!(live_sliders(), 1);
+
+        app.destroy();
+        sleep(Duration::ZERO).await;
+        root.remove();
+        assert_eq!(live_sliders(), 0);
+    }
+    assert_eq!(bound_events(), 0);
+    // Would throw if a destroyed slider still held a dropped closure
+    fire_all();
+}
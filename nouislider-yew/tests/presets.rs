@@ -0,0 +1,64 @@
+#[cfg(feature = "chrono")]
+mod chrono_backend {
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    use nouislider_yew::{CalendarUnit, DatePreset, RelativeRange};
+    use std::time::Duration;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    // Wednesday 2024-03-13 10:00 at UTC+2
+    fn now() -> DateTime<FixedOffset> {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        tz.with_ymd_and_hms(2024, 3, 13, 10, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn relative_ranges() {
+        let resolve = |range: RelativeRange| {
+            let (start, end) = range.resolve(&now());
+            (start.to_utc(), end.to_utc())
+        };
+        assert_eq!(
+            resolve(RelativeRange::Last(Duration::from_secs(86400))),
+            (utc(2024, 3, 12, 8), utc(2024, 3, 13, 8))
+        );
+        assert_eq!(
+            resolve(RelativeRange::Current(CalendarUnit::Month)),
+            (utc(2024, 2, 29, 22), utc(2024, 3, 31, 22))
+        );
+        assert_eq!(
+            resolve(RelativeRange::ToDate(CalendarUnit::Year)),
+            (utc(2023, 12, 31, 22), utc(2024, 3, 13, 8))
+        );
+        assert_eq!(
+            resolve(RelativeRange::Previous(CalendarUnit::Week)),
+            (utc(2024, 3, 3, 22), utc(2024, 3, 10, 22))
+        );
+    }
+
+    #[test]
+    fn presets_are_limited_to_the_slider_range() {
+        let preset = DatePreset::new(
+            "Last 7 days",
+            RelativeRange::Last(Duration::from_secs(604800)),
+        );
+        let min = utc(2024, 3, 10, 0).fixed_offset();
+        let max = utc(2024, 3, 12, 0).fixed_offset();
+        assert_eq!(preset.resolve(&now(), &min, &max), vec![min, max]);
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_backend {
+    use nouislider_yew::{CalendarUnit, RelativeRange};
+
+    #[test]
+    fn calendar_ranges() {
+        let now = time::macros::datetime!(2024-03-13 10:00 +2);
+        let (start, end) = RelativeRange::Previous(CalendarUnit::Month).resolve(&now);
+        assert_eq!(start, time::macros::datetime!(2024-02-01 00:00 +2));
+        assert_eq!(end, time::macros::datetime!(2024-03-01 00:00 +2));
+    }
+}
@@ -0,0 +1,36 @@
+use nouislider_yew::RangeIndex;
+
+type Row = (&'static str, f64);
+
+fn index() -> RangeIndex<Row> {
+    let rows = [("c", 3.0), ("a", 1.0), ("d", 4.0), ("b", 2.0), ("b2", 2.0)];
+    RangeIndex::new(rows, |row| row.1)
+}
+
+fn names(rows: &[Row]) -> Vec<&str> {
+    rows.iter().map(|row| row.0).collect()
+}
+
+#[test]
+fn range_includes_both_ends() {
+    let index = index();
+    assert_eq!(names(index.range(2.0, 3.0)), ["b", "b2", "c"]);
+    assert_eq!(index.count(1.5, 3.5), 3);
+    assert_eq!(index.count(5.0, 6.0), 0);
+    assert_eq!(index.count(3.0, 2.0), 0);
+}
+
+#[test]
+fn selected_follows_handles() {
+    let index = index();
+    assert_eq!(names(index.selected(&[3.0, 2.0])), ["b", "b2", "c"]);
+    assert_eq!(names(index.selected(&[2.0])), ["a", "b", "b2"]);
+}
+
+#[test]
+fn insert_keeps_order() {
+    let mut index = index();
+    index.insert(2.0, ("b3", 2.0));
+    index.insert(0.0, ("z", 0.0));
+    assert_eq!(names(index.items()), ["z", "a", "b", "b2", "b3", "c", "d"]);
+}
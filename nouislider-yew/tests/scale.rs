@@ -0,0 +1,32 @@
+use nouislider_yew::{Linear, Log, Pow, Sqrt, TrackMapping, ValueScale};
+
+fn round_trip(scale: &impl ValueScale, values: &[f64]) {
+    for value in values {
+        assert_eq!(scale.to_value(scale.to_track(*value)), *value);
+    }
+}
+
+#[test]
+fn scales_round_trip() {
+    round_trip(&Linear, &[-2.5, 0.0, 7.0]);
+    round_trip(&Log, &[20.0, 440.0, 1000.0, 20000.0]);
+    round_trip(&Pow(2.0), &[-3.0, 0.0, 0.1, 5.0]);
+    round_trip(&Pow(0.25), &[-16.0, 0.0, 81.0]);
+    round_trip(&Sqrt, &[0.0, 2.0, 9.0, 1e6]);
+}
+
+#[test]
+fn scales_on_the_track() {
+    assert_eq!(Log.to_track(1.0), 0.0);
+    assert_eq!(Pow(2.0).to_track(-3.0), -9.0);
+    assert_eq!(Sqrt.to_track(9.0), 3.0);
+}
+
+#[test]
+fn track_mapping_of_a_scale() {
+    let mapping = TrackMapping::from(Log);
+    assert_eq!(mapping.to_track.emit(1.0), 0.0);
+    for value in [20.0, 440.0, 20000.0] {
+        assert_eq!(mapping.from_track.emit(mapping.to_track.emit(value)), value);
+    }
+}
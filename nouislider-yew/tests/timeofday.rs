@@ -0,0 +1,30 @@
+use nouislider_yew::{format_time_of_day, time_of_day};
+
+#[test]
+fn end_of_day_shows_as_24() {
+    assert_eq!(format_time_of_day(86400.0, false), "24:00");
+    assert_eq!(format_time_of_day(86399.0, false), "24:00");
+    assert_eq!(format_time_of_day(0.0, false), "00:00");
+    assert_eq!(format_time_of_day(86400.0, true), "12:00 AM");
+    assert_eq!(format_time_of_day(12.0 * 3600.0, true), "12:00 PM");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn end_of_day_is_the_last_second() {
+    use chrono::NaiveTime;
+
+    let end: NaiveTime = time_of_day(86400.0);
+    assert_eq!(end, NaiveTime::from_hms_opt(23, 59, 59).unwrap());
+    let midnight: NaiveTime = time_of_day(0.0);
+    assert_eq!(midnight, NaiveTime::MIN);
+    assert!(midnight < end);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn end_of_day_is_the_last_second_with_time() {
+    let end: time::Time = time_of_day(86400.0);
+    assert_eq!(end, time::macros::time!(23:59:59));
+    assert_eq!(time_of_day::<time::Time>(0.0), time::Time::MIDNIGHT);
+}
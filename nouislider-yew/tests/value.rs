@@ -0,0 +1,77 @@
+use nouislider_yew::SliderValue;
+
+#[cfg(feature = "chrono")]
+#[test]
+fn fixed_offset_round_trip() {
+    use chrono::{DateTime, FixedOffset, TimeZone};
+
+    let zone = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let date = zone.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap();
+    let text = SliderValue::format(&date);
+    assert_eq!(text, "2024-03-31 02:30 +0530");
+    assert_eq!(
+        <DateTime<FixedOffset> as SliderValue>::parse(&text),
+        Some(date)
+    );
+    assert_eq!(
+        <DateTime<FixedOffset> as SliderValue>::parse("2024-03-31 02:30"),
+        None
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn offset_date_time_round_trip() {
+    let date = time::macros::datetime!(2024-03-31 02:30 -03:00);
+    let text = SliderValue::format(&date);
+    assert_eq!(text, "2024-03-31 02:30 -0300");
+    assert_eq!(
+        <time::OffsetDateTime as SliderValue>::parse(&text),
+        Some(date)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn invalid_chrono_format_falls_back() {
+    use chrono::{TimeZone, Utc};
+    use nouislider_yew::SliderDate;
+
+    let date = Utc.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap();
+    assert_eq!(date.format_with("%d %b"), "31 Mar");
+    assert_eq!(date.format_with("%Q"), "2024-03-31 02:30");
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn invalid_time_format_falls_back() {
+    use nouislider_yew::SliderDate;
+
+    let date = time::macros::datetime!(2024-03-31 02:30 UTC);
+    assert_eq!(date.format_with("[day] [month repr:short]"), "31 Mar");
+    assert_eq!(date.format_with("[nonsense"), "2024-03-31 02:30");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn fixed_offset_from_track_keeps_offset() {
+    use chrono::{DateTime, FixedOffset, TimeZone};
+
+    let zone = FixedOffset::east_opt(2 * 3600).unwrap();
+    let min = zone.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let date = zone.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap();
+    let value = DateTime::<FixedOffset>::from_f64_like(date.to_f64(), &min);
+    assert_eq!(value, date);
+    assert_eq!(value.offset(), &zone);
+    assert_eq!(SliderValue::format(&value), "2024-03-31 02:30 +0200");
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn offset_date_time_from_track_keeps_offset() {
+    let min = time::macros::datetime!(2024-03-01 00:00 -03:00);
+    let date = time::macros::datetime!(2024-03-31 02:30 -03:00);
+    let value = time::OffsetDateTime::from_f64_like(date.to_f64(), &min);
+    assert_eq!(value.offset(), min.offset());
+    assert_eq!(SliderValue::format(&value), "2024-03-31 02:30 -0300");
+}